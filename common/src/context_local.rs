@@ -0,0 +1,77 @@
+//! A cache keyed by `Context` identity, for native-module caches that
+//! need to survive across calls but must not leak between independent
+//! interpreters.
+//!
+//! [`crate::static_cell!`] is a single process-wide (or, without the
+//! `threading` feature, thread-wide) slot: exactly the wrong shape for a
+//! cache that's supposed to belong to one `Context`, since a second
+//! `Interpreter` -- with its own types, singletons and interned strings
+//! -- would see the first one's cached value and hand out an object
+//! that belongs to a different `Context` entirely. `ContextLocal` fixes
+//! that by keying the cache on the identity of whatever `Context` (or
+//! other per-interpreter handle) is asking for it, so each interpreter
+//! gets its own slot and they never cross.
+use crate::lock::PyMutex;
+use crate::rc::PyRc;
+use std::collections::HashMap;
+
+#[cfg(feature = "threading")]
+type Weak<T> = std::sync::Weak<T>;
+#[cfg(not(feature = "threading"))]
+type Weak<T> = std::rc::Weak<T>;
+
+/// A value cached per-`H` (typically `Context`), identified by the pointer
+/// address of a `PyRc<H>`.
+///
+/// Entries are never evicted: like `static_cell!`, this trades a bounded
+/// amount of memory per interpreter for simplicity, on the assumption
+/// that interpreters are created far less often than the cache is read.
+/// Each entry holds a `Weak<H>` alongside its value, both to detect a dead
+/// owner (so a stale value is never handed back) and, just as importantly,
+/// to keep that owner's allocation pinned for as long as we hold the entry
+/// -- without that, a dropped `Context`'s address could be handed to a
+/// brand new `Context` by the allocator, and a bare pointer-address key
+/// would then silently return the old `Context`'s cached value for it.
+pub struct ContextLocal<H, T> {
+    slots: PyMutex<HashMap<usize, (Weak<H>, T)>>,
+}
+
+impl<H, T> Default for ContextLocal<H, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H, T> ContextLocal<H, T> {
+    pub const fn new() -> Self {
+        Self {
+            slots: PyMutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<H, T: Clone> ContextLocal<H, T> {
+    /// Returns the value cached for `key`, initializing it with `f` the
+    /// first time `key` is seen -- or if the `H` previously seen at this
+    /// address has since been dropped.
+    pub fn get_or_init(&self, key: &PyRc<H>, f: impl FnOnce() -> T) -> T {
+        let ptr = PyRc::as_ptr(key) as usize;
+        let mut slots = self.slots.lock();
+        if let Some((owner, value)) = slots.get(&ptr)
+            && owner.upgrade().is_some()
+        {
+            return value.clone();
+        }
+        let value = f();
+        slots.insert(ptr, (PyRc::downgrade(key), value.clone()));
+        value
+    }
+}
+
+#[macro_export]
+macro_rules! context_local {
+    ($($(#[$attr:meta])* $vis:vis static $name:ident: $h:ty => $t:ty;)+) => {
+        $($(#[$attr])*
+        $vis static $name: $crate::context_local::ContextLocal<$h, $t> = $crate::context_local::ContextLocal::new();)+
+    };
+}
@@ -10,6 +10,7 @@ pub mod atomic;
 pub mod borrow;
 pub mod boxvec;
 pub mod cformat;
+pub mod context_local;
 #[cfg(any(unix, windows, target_os = "wasi"))]
 pub mod crt_fd;
 pub mod encodings;
@@ -24,6 +24,9 @@ pub use immutable_mutex::*;
 mod thread_mutex;
 pub use thread_mutex::*;
 
+#[cfg(all(test, loom))]
+mod loom_tests;
+
 pub type PyMutex<T> = Mutex<RawMutex, T>;
 pub type PyMutexGuard<'a, T> = MutexGuard<'a, RawMutex, T>;
 pub type PyMappedMutexGuard<'a, T> = MappedMutexGuard<'a, RawMutex, T>;
@@ -33,6 +33,13 @@ impl RefCount {
 
     #[inline]
     pub fn inc(&self) {
+        // Immortal objects (see `leak`/`is_leaked` below) never need their
+        // count touched: skip the fetch_add so their cache line stays
+        // shared across threads instead of bouncing on every inc/dec.
+        if self.is_leaked() {
+            return;
+        }
+
         let old_size = self.strong.fetch_add(1, Relaxed);
 
         if old_size & Self::MASK == Self::MASK {
@@ -43,6 +50,9 @@ impl RefCount {
     /// Returns true if successful
     #[inline]
     pub fn safe_inc(&self) -> bool {
+        if self.is_leaked() {
+            return true;
+        }
         self.strong
             .fetch_update(AcqRel, Acquire, |prev| (prev != 0).then_some(prev + 1))
             .is_ok()
@@ -51,6 +61,10 @@ impl RefCount {
     /// Decrement the reference count. Returns true when the refcount drops to 0.
     #[inline]
     pub fn dec(&self) -> bool {
+        if self.is_leaked() {
+            return false;
+        }
+
         if self.strong.fetch_sub(1, Release) != 1 {
             return false;
         }
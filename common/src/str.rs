@@ -318,6 +318,13 @@ impl StrData {
         len
     }
 
+    /// O(1) for `Ascii` (direct byte index), but O(n) for `Utf8`/`Wtf8` --
+    /// those are stored as variable-width (W)UTF-8, not a fixed-width
+    /// per-codepoint array, so reaching the nth code point means walking
+    /// from the start. Unlike `char_len`, there's no O(1) fix for this that
+    /// doesn't also change the underlying storage (e.g. a PEP 393-style
+    /// Latin-1/UCS-2/UCS-4 split), which is a far bigger change than this
+    /// method alone.
     pub fn nth_char(&self, index: usize) -> CodePoint {
         match self.as_str_kind() {
             PyKindStr::Ascii(s) => s[index].into(),
@@ -236,6 +236,33 @@ macro_rules! emit {
     };
 }
 
+/// Report a `SyntaxWarning: invalid escape sequence` for every offset in
+/// `invalid_escapes`, each relative to the start of `literal_range`. CPython
+/// raises these through its regular `warnings` filter machinery, but codegen
+/// runs before a VM (and therefore a `warnings` module) exists, so -- like
+/// [`eprint_location`] below -- we fall back to the same unfiltered stderr
+/// write CPython itself uses when `warnings` can't be imported yet.
+fn warn_invalid_escapes(
+    zelf: &Compiler,
+    literal_range: TextRange,
+    invalid_escapes: &[usize],
+) {
+    for &offset in invalid_escapes {
+        let Some(offset) = ruff_text_size::TextSize::try_from(offset).ok() else {
+            continue;
+        };
+        let location = zelf
+            .source_file
+            .to_source_code()
+            .source_location(literal_range.start() + offset);
+        eprintln!(
+            "{}:{}: SyntaxWarning: invalid escape sequence",
+            zelf.source_file.name(),
+            location.row,
+        );
+    }
+}
+
 fn eprint_location(zelf: &Compiler) {
     let start = zelf
         .source_file
@@ -971,10 +998,16 @@ impl Compiler {
             // Check parent scope type
             let parent_obj_name = &parent.metadata.name;
 
-            // Determine if parent is a function-like scope
+            // Determine if parent is a function-like scope. Lambdas count
+            // (they're just unnamed functions), but comprehensions/genexps
+            // don't: a name nested directly inside one is still considered
+            // local to the *enclosing function*, not to the comprehension,
+            // so no extra `.<locals>.` segment is added for it.
             let is_function_parent = parent.flags.contains(bytecode::CodeFlags::IS_OPTIMIZED)
-                && !parent_obj_name.starts_with("<") // Not a special scope like <lambda>, <listcomp>, etc.
-                && parent_obj_name != "<module>"; // Not the module scope
+                && !matches!(
+                    parent_obj_name.as_str(),
+                    "<listcomp>" | "<setcomp>" | "<dictcomp>" | "<genexpr>" | "<module>"
+                );
 
             if is_function_parent {
                 // For functions, append .<locals> to parent qualname
@@ -4496,20 +4529,39 @@ impl Compiler {
     }
 
     fn compile_dict(&mut self, items: &[DictItem]) -> CompileResult<()> {
-        // FIXME: correct order to build map, etc d = {**a, 'key': 2} should override
-        // 'key' in dict a
-        let mut size = 0;
-        let (packed, unpacked): (Vec<_>, Vec<_>) = items.iter().partition(|x| x.key.is_some());
-        for item in packed {
-            self.compile_expression(item.key.as_ref().unwrap())?;
-            self.compile_expression(&item.value)?;
-            size += 1;
+        // Keys and values are evaluated left to right, and a later entry (whether a literal
+        // `key: value` or a `**mapping` unpack) overrides an earlier one for the same key, so
+        // contiguous runs of literal pairs and `**` unpacks must be compiled and merged in the
+        // order they appear rather than grouped by kind.
+        let mut base_built = false;
+        let groupby = items.iter().chunk_by(|item| item.key.is_none());
+        for (is_unpacking, group) in &groupby {
+            if is_unpacking {
+                if !base_built {
+                    emit!(self, Instruction::BuildMap { size: 0 });
+                    base_built = true;
+                }
+                for item in group {
+                    self.compile_expression(&item.value)?;
+                    emit!(self, Instruction::DictUpdate { index: 1 });
+                }
+            } else {
+                let mut size = 0;
+                for item in group {
+                    self.compile_expression(item.key.as_ref().unwrap())?;
+                    self.compile_expression(&item.value)?;
+                    size += 1;
+                }
+                emit!(self, Instruction::BuildMap { size });
+                if base_built {
+                    emit!(self, Instruction::DictUpdate { index: 1 });
+                } else {
+                    base_built = true;
+                }
+            }
         }
-        emit!(self, Instruction::BuildMap { size });
-
-        for item in unpacked {
-            self.compile_expression(&item.value)?;
-            emit!(self, Instruction::DictUpdate { index: 1 });
+        if !base_built {
+            emit!(self, Instruction::BuildMap { size: 0 });
         }
 
         Ok(())
@@ -4889,12 +4941,21 @@ impl Compiler {
                         .iter()
                         .map(|lit| {
                             let source = self.source_file.slice(lit.range);
-                            crate::string_parser::parse_string_literal(source, lit.flags.into())
+                            let (value, invalid_escapes) =
+                                crate::string_parser::parse_string_literal(source, lit.flags.into());
+                            warn_invalid_escapes(self, lit.range, &invalid_escapes);
+                            value
                         })
                         .collect();
                     // might have a surrogate literal; should reparse to be sure
                     self.emit_load_const(ConstantData::Str { value });
                 } else {
+                    for lit in string.value.iter() {
+                        let source = self.source_file.slice(lit.range);
+                        let invalid_escapes =
+                            crate::string_parser::scan_invalid_escapes(source, lit.flags.into());
+                        warn_invalid_escapes(self, lit.range, &invalid_escapes);
+                    }
                     self.emit_load_const(ConstantData::Str {
                         value: value.into(),
                     });
@@ -5579,12 +5640,17 @@ impl Compiler {
                 if string.value.contains(char::REPLACEMENT_CHARACTER) {
                     // might have a surrogate literal; should reparse to be sure
                     let source = self.source_file.slice(string.range);
-                    let value =
+                    let (value, invalid_escapes) =
                         crate::string_parser::parse_string_literal(source, string.flags.into());
+                    warn_invalid_escapes(self, string.range, &invalid_escapes);
                     self.emit_load_const(ConstantData::Str {
                         value: value.into(),
                     });
                 } else {
+                    let source = self.source_file.slice(string.range);
+                    let invalid_escapes =
+                        crate::string_parser::scan_invalid_escapes(source, string.flags.into());
+                    warn_invalid_escapes(self, string.range, &invalid_escapes);
                     self.emit_load_const(ConstantData::Str {
                         value: string.value.to_string().into(),
                     });
@@ -5612,14 +5678,22 @@ impl Compiler {
                     if string.value.contains(char::REPLACEMENT_CHARACTER) {
                         // might have a surrogate literal; should reparse to be sure
                         let source = self.source_file.slice(string.range);
-                        let value = crate::string_parser::parse_fstring_literal_element(
-                            source.into(),
-                            flags.into(),
-                        );
+                        let (value, invalid_escapes) =
+                            crate::string_parser::parse_fstring_literal_element(
+                                source.into(),
+                                flags.into(),
+                            );
+                        warn_invalid_escapes(self, string.range, &invalid_escapes);
                         self.emit_load_const(ConstantData::Str {
                             value: value.into(),
                         });
                     } else {
+                        let source = self.source_file.slice(string.range);
+                        let invalid_escapes = crate::string_parser::scan_fstring_invalid_escapes(
+                            source.into(),
+                            flags.into(),
+                        );
+                        warn_invalid_escapes(self, string.range, &invalid_escapes);
                         self.emit_load_const(ConstantData::Str {
                             value: string.value.to_string().into(),
                         });
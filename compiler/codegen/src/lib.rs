@@ -5,6 +5,9 @@
 #[macro_use]
 extern crate log;
 
+// ahash rather than the default SipHash: `names`/`varnames`/`consts` are
+// rebuilt from scratch for every code object, so lookup speed during
+// compilation matters more than DoS resistance here.
 type IndexMap<K, V> = indexmap::IndexMap<K, V, ahash::RandomState>;
 type IndexSet<T> = indexmap::IndexSet<T, ahash::RandomState>;
 
@@ -25,6 +25,12 @@ struct StringParser {
     cursor: usize,
     /// Flags that can be used to query information about the string.
     flags: AnyStringFlags,
+    /// Byte offsets (relative to `source`) of the backslash of every
+    /// escape sequence we don't recognize, e.g. `\d` in `"\d+"`. CPython
+    /// warns on these (`SyntaxWarning: invalid escape sequence '\d'`)
+    /// instead of rejecting them outright, since they're still valid as
+    /// a literal backslash followed by the next character.
+    invalid_escapes: Vec<usize>,
 }
 
 impl StringParser {
@@ -33,6 +39,7 @@ impl StringParser {
             source,
             cursor: 0,
             flags,
+            invalid_escapes: Vec::new(),
         }
     }
 
@@ -147,16 +154,20 @@ impl StringParser {
 
                 return Ok(None);
             }
-            _ => return Ok(Some(EscapedChar::Escape(first_char))),
+            _ => {
+                self.invalid_escapes
+                    .push(self.cursor - first_char.len_utf8() - 1);
+                return Ok(Some(EscapedChar::Escape(first_char)));
+            }
         };
 
         Ok(Some(EscapedChar::Literal(new_char)))
     }
 
-    fn parse_fstring_middle(mut self) -> Result<Box<Wtf8>, LexicalError> {
+    fn parse_fstring_middle(mut self) -> Result<(Box<Wtf8>, Vec<usize>), LexicalError> {
         // Fast-path: if the f-string doesn't contain any escape sequences, return the literal.
         let Some(mut index) = memchr::memchr3(b'{', b'}', b'\\', self.source.as_bytes()) else {
-            return Ok(self.source.into());
+            return Ok((self.source.into(), self.invalid_escapes));
         };
 
         let mut value = Wtf8Buf::with_capacity(self.source.len());
@@ -224,18 +235,18 @@ impl StringParser {
             index = next_index;
         }
 
-        Ok(value.into())
+        Ok((value.into(), self.invalid_escapes))
     }
 
-    fn parse_string(mut self) -> Result<Box<Wtf8>, LexicalError> {
+    fn parse_string(mut self) -> Result<(Box<Wtf8>, Vec<usize>), LexicalError> {
         if self.flags.is_raw_string() {
             // For raw strings, no escaping is necessary.
-            return Ok(self.source.into());
+            return Ok((self.source.into(), self.invalid_escapes));
         }
 
         let Some(mut escape) = memchr::memchr(b'\\', self.source.as_bytes()) else {
             // If the string doesn't contain any escape sequences, return the owned string.
-            return Ok(self.source.into());
+            return Ok((self.source.into(), self.invalid_escapes));
         };
 
         // If the string contains escape sequences, we need to parse them.
@@ -268,20 +279,46 @@ impl StringParser {
             escape = next_escape;
         }
 
-        Ok(value.into())
+        Ok((value.into(), self.invalid_escapes))
     }
 }
 
-pub(crate) fn parse_string_literal(source: &str, flags: AnyStringFlags) -> Box<Wtf8> {
-    let source = &source[flags.opener_len().to_usize()..];
-    let source = &source[..source.len() - flags.quote_len().to_usize()];
-    StringParser::new(source.into(), flags)
+/// Offsets are relative to the start of the *unstripped* literal (i.e. they
+/// already account for the opening quote/prefix), so callers can add them
+/// directly to the literal's own source range.
+pub(crate) fn parse_string_literal(
+    source: &str,
+    flags: AnyStringFlags,
+) -> (Box<Wtf8>, Vec<usize>) {
+    let opener_len = flags.opener_len().to_usize();
+    let stripped = &source[opener_len..];
+    let stripped = &stripped[..stripped.len() - flags.quote_len().to_usize()];
+    let (value, offsets) = StringParser::new(stripped.into(), flags)
         .parse_string()
-        .unwrap_or_else(|x| match x {})
+        .unwrap_or_else(|x| match x {});
+    let offsets = offsets.into_iter().map(|offset| offset + opener_len).collect();
+    (value, offsets)
 }
 
-pub(crate) fn parse_fstring_literal_element(source: Box<str>, flags: AnyStringFlags) -> Box<Wtf8> {
+pub(crate) fn parse_fstring_literal_element(
+    source: Box<str>,
+    flags: AnyStringFlags,
+) -> (Box<Wtf8>, Vec<usize>) {
     StringParser::new(source, flags)
         .parse_fstring_middle()
         .unwrap_or_else(|x| match x {})
 }
+
+/// Like [`parse_string_literal`], but only the offsets of its invalid escape
+/// sequences are needed -- used on the common (non-surrogate) path, where
+/// the literal's value was already supplied by the parser and doesn't need
+/// to be rebuilt, but CPython-compatible `SyntaxWarning`s still do.
+pub(crate) fn scan_invalid_escapes(source: &str, flags: AnyStringFlags) -> Vec<usize> {
+    parse_string_literal(source, flags).1
+}
+
+/// Like [`scan_invalid_escapes`], for the content of an f-string literal
+/// segment (no surrounding quotes to strip).
+pub(crate) fn scan_fstring_invalid_escapes(source: Box<str>, flags: AnyStringFlags) -> Vec<usize> {
+    parse_fstring_literal_element(source, flags).1
+}
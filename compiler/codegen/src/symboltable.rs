@@ -1738,19 +1738,40 @@ impl SymbolTableBuilder {
     }
 }
 
+/// Apply PEP 3131 identifier normalization: every identifier is compared
+/// and stored in its NFKC form, so that e.g. `ℌ𝔢𝔩𝔩𝔬` and `Hello` name the
+/// same binding. CPython does this in its tokenizer; our tokenizer lives in
+/// the out-of-tree `ruff_python_parser` crate, so we normalize here instead,
+/// at the single place both the symbol table and codegen resolve a `Name`
+/// node's identifier down to the string they actually bind/look up.
+fn normalize_identifier(name: &str) -> Cow<'_, str> {
+    if name.is_ascii() {
+        // NFKC is a no-op on ASCII; skip the allocation in the common case.
+        return Cow::Borrowed(name);
+    }
+    use unic_normal::StrNormalForm;
+    let normalized: String = name.nfkc().collect();
+    if normalized == name {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
+
 pub(crate) fn mangle_name<'a>(class_name: Option<&str>, name: &'a str) -> Cow<'a, str> {
+    let name = normalize_identifier(name);
     let class_name = match class_name {
         Some(n) => n,
-        None => return name.into(),
+        None => return name,
     };
     if !name.starts_with("__") || name.ends_with("__") || name.contains('.') {
-        return name.into();
+        return name;
     }
     // strip leading underscore
     let class_name = class_name.strip_prefix(|c| c == '_').unwrap_or(class_name);
     let mut ret = String::with_capacity(1 + class_name.len() + name.len());
     ret.push('_');
     ret.push_str(class_name);
-    ret.push_str(name);
+    ret.push_str(&name);
     ret.into()
 }
@@ -416,7 +416,7 @@ fn generate_class_def(
     Ok(tokens)
 }
 
-pub(crate) fn impl_pyclass(attr: PunctuatedNestedMeta, item: Item) -> Result<TokenStream> {
+pub(crate) fn impl_pyclass(attr: PunctuatedNestedMeta, mut item: Item) -> Result<TokenStream> {
     if matches!(item, syn::Item::Use(_)) {
         return Ok(quote!(#item));
     }
@@ -513,6 +513,13 @@ pub(crate) fn impl_pyclass(attr: PunctuatedNestedMeta, item: Item) -> Result<Tok
         quote! {}
     };
 
+    // `#[pyfield]` lets embedder code expose a read-only getter straight from
+    // a struct field instead of hand-writing a `#[pygetset]` method, so we
+    // strip it here and synthesize the getter into its own `#[pyclass] impl`
+    // block -- that block gets re-expanded by the outer `#[pyclass]`
+    // invocation the same way `empty_impl` above does.
+    let field_getters = generate_field_getters(ident.clone(), &mut item)?;
+
     let ret = quote! {
         #derive_trace
         #item
@@ -520,10 +527,56 @@ pub(crate) fn impl_pyclass(attr: PunctuatedNestedMeta, item: Item) -> Result<Tok
         #class_def
         #impl_payload
         #empty_impl
+        #field_getters
     };
     Ok(ret)
 }
 
+/// Strip `#[pyfield]` attributes off of `item`'s fields (if it's a struct)
+/// and generate a matching read-only `#[pygetset]` getter for each one, e.g.
+/// `#[pyfield] name: PyStrRef` becomes a `name(&self) -> PyStrRef` getter
+/// that clones the field. Setters aren't generated: safely mutating an
+/// arbitrary field from Python needs interior mutability that varies per
+/// type, so that still has to be hand-written as a `#[pygetset(setter)]`.
+fn generate_field_getters(ident: Ident, item: &mut Item) -> Result<TokenStream> {
+    let Item::Struct(item_struct) = item else {
+        return Ok(quote! {});
+    };
+    let mut getters = Vec::new();
+    for field in item_struct.fields.iter_mut() {
+        let Some(attr_index) = field
+            .attrs
+            .iter()
+            .position(|attr| attr.path().is_ident("pyfield"))
+        else {
+            continue;
+        };
+        let attr = field.attrs.remove(attr_index);
+        let field_ident = field.ident.clone().ok_or_else(|| {
+            err_span!(field, "#[pyfield] can only be used on named struct fields")
+        })?;
+        let meta = SimpleItemMeta::from_attr(field_ident.clone(), &attr)?;
+        let py_name = meta.simple_name()?;
+        let field_ty = &field.ty;
+        getters.push(quote! {
+            #[pygetset(name = #py_name)]
+            fn #field_ident(&self) -> #field_ty {
+                ::std::clone::Clone::clone(&self.#field_ident)
+            }
+        });
+    }
+    Ok(if getters.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            #[pyclass]
+            impl #ident {
+                #(#getters)*
+            }
+        }
+    })
+}
+
 /// Special macro to create exception types.
 ///
 /// Why do we need it and why can't we just use `pyclass` macro instead?
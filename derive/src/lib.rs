@@ -92,6 +92,24 @@ pub fn derive_from_args(input: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+/// ### pyfield
+/// This is a struct field attribute (not a method attribute) that generates a read-only
+/// `pygetset` getter for the field, so embedders don't need to hand-write one for every
+/// field they want to expose.
+/// #### Arguments
+/// - `name`: the name of the attribute in Python, by default it is the same as the field.
+/// #### Examples
+/// ```rust, ignore
+/// #[pyclass(module = false, name = "MyStruct")]
+/// #[derive(PyPayload)]
+/// struct MyStruct {
+///     #[pyfield]
+///     x: i32,
+/// }
+/// ```
+/// Setters are not generated, since safely mutating a field from Python requires whatever
+/// interior mutability the field actually uses; write a `#[pygetset(setter)]` method by hand
+/// for that.
 /// ### pyslot
 /// This is used to mark a slot method it should be marked by prefixing the method in rust with `slot_`.
 /// #### Arguments
@@ -0,0 +1,26 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, arbitrary::Arbitrary)]
+struct DictArgs {
+    keys: Vec<String>,
+    values: Vec<i64>,
+    lookup_key: String,
+    pop_key: String,
+}
+
+fuzz_target!(|args: DictArgs| {
+    let pairs: String = args
+        .keys
+        .iter()
+        .zip(args.values.iter().chain(std::iter::repeat(&0)))
+        .map(|(k, v)| format!("{k:?}: {v}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let source = format!(
+        "d = {{{pairs}}}\nd.get({:?})\nd.pop({:?}, None)\nd.update({{{pairs}}})\nlist(d.items())\nlist(reversed(d))",
+        args.lookup_key, args.pop_key,
+    );
+    rustpython_fuzz::run_snippet(&source);
+});
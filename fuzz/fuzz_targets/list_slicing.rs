@@ -0,0 +1,31 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, arbitrary::Arbitrary)]
+struct SliceArgs {
+    items: Vec<i64>,
+    start: Option<i64>,
+    stop: Option<i64>,
+    step: Option<i64>,
+    repeat: i64,
+}
+
+fn py_opt(v: Option<i64>) -> String {
+    v.map_or_else(String::new, |v| v.to_string())
+}
+
+fuzz_target!(|args: SliceArgs| {
+    let source = format!(
+        "lst = {:?}\nx = lst[{}:{}:{}]\nlist(x)\nlst * {}\nlst[{}:{}:{}] = []",
+        args.items,
+        py_opt(args.start),
+        py_opt(args.stop),
+        py_opt(args.step),
+        args.repeat,
+        py_opt(args.start),
+        py_opt(args.stop),
+        py_opt(args.step),
+    );
+    rustpython_fuzz::run_snippet(&source);
+});
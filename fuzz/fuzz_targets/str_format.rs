@@ -0,0 +1,37 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, arbitrary::Arbitrary)]
+struct FormatArgs {
+    value: i64,
+    fill: char,
+    align: char,
+    sign: char,
+    width: u8,
+    precision: u8,
+    grouping: char,
+    ty: char,
+}
+
+fuzz_target!(|args: FormatArgs| {
+    // Not every combination is a valid format spec (e.g. `ty` may not be one
+    // CPython recognizes); invalid specs are expected to raise ValueError,
+    // which run_snippet already treats as a non-panic outcome.
+    let spec = format!(
+        "{}{}{}{}{}.{}{}{}",
+        args.fill,
+        args.align,
+        args.sign,
+        "",
+        args.width,
+        args.precision,
+        args.grouping,
+        args.ty,
+    );
+    let source = format!(
+        "format({}, {:?})\n\"{{:{}}}\".format({})",
+        args.value, spec, spec, args.value,
+    );
+    rustpython_fuzz::run_snippet(&source);
+});
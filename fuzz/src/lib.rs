@@ -0,0 +1,21 @@
+//! Shared harness code for the `fuzz_targets` binaries in this crate.
+//!
+//! Each target feeds fuzzer-generated input into a small snippet of Python
+//! source and runs it through the VM. A Python-level exception (`PyResult::Err`)
+//! is an expected outcome and is ignored; a Rust panic is the bug we're
+//! looking for, since builtin methods are supposed to turn bad input into
+//! exceptions rather than ever unwinding through a panic.
+
+use rustpython_vm::{Interpreter, compiler::Mode};
+
+/// Run `source` to completion, discarding any raised Python exception.
+/// Panics from the VM itself are intentionally allowed to propagate.
+pub fn run_snippet(source: &str) {
+    Interpreter::without_stdlib(Default::default()).enter(|vm| {
+        let scope = vm.new_scope_with_builtins();
+        let Ok(code) = vm.compile(source, Mode::Exec, "<fuzz>".to_owned()) else {
+            return;
+        };
+        let _ = vm.run_code_obj(code, scope);
+    });
+}
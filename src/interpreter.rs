@@ -37,10 +37,33 @@ pub type InitHook = Box<dyn FnOnce(&mut VirtualMachine)>;
 ///     }))
 ///     .interpreter();
 /// ```
-#[derive(Default)]
+///
+/// To deny capabilities the embedded script shouldn't need:
+/// ```
+/// let interpreter = rustpython::InterpreterConfig::new()
+///     .allow_network(false)
+///     .allow_subprocess(false)
+///     .init_stdlib()
+///     .interpreter();
+/// ```
 pub struct InterpreterConfig {
     settings: Option<Settings>,
     init_hooks: Vec<InitHook>,
+    allow_filesystem: bool,
+    allow_network: bool,
+    allow_subprocess: bool,
+}
+
+impl Default for InterpreterConfig {
+    fn default() -> Self {
+        Self {
+            settings: None,
+            init_hooks: Vec::new(),
+            allow_filesystem: true,
+            allow_network: true,
+            allow_subprocess: true,
+        }
+    }
 }
 
 impl InterpreterConfig {
@@ -73,15 +96,84 @@ impl InterpreterConfig {
             vm.add_native_module(name, Box::new(make_module))
         }))
     }
+
+    /// Whether to allow the interpreter to touch the real filesystem.
+    /// Defaults to `true`. In addition to keeping `mmap` out of
+    /// `sys.modules`, `false` also turns `open`/`os.open`/`os.remove`/
+    /// `os.rename`/`os.mkdir`/`os.rmdir`/`os.listdir` into a
+    /// `PermissionError`, since the latter live in `os`/`posix`/`nt`/`io`,
+    /// which importlib depends on and which are therefore always
+    /// registered. An embedder-installed [`rustpython_vm::vfs`] filesystem is
+    /// unaffected by this toggle -- reads served from it never reach the
+    /// real filesystem in the first place.
+    pub fn allow_filesystem(mut self, allow: bool) -> Self {
+        self.allow_filesystem = allow;
+        self
+    }
+
+    /// Whether to register the native modules that provide networking
+    /// (`_socket`, `_net`, `select`, `_ssl`). Defaults to `true`.
+    ///
+    /// `os`/`posix`/`nt` don't expose a raw socket syscall of their own in
+    /// this tree, so unlike [`Self::allow_subprocess`] there's no always-
+    /// present escape hatch to also neuter here -- but neither of these
+    /// toggles is a security boundary against arbitrary native code: a
+    /// script that can reach `ctypes` (or any other native-call surface)
+    /// can still make the underlying syscalls directly.
+    pub fn allow_network(mut self, allow: bool) -> Self {
+        self.allow_network = allow;
+        self
+    }
+
+    /// Whether to allow the interpreter to spawn processes. Defaults to
+    /// `true`. In addition to keeping `_posixsubprocess`/`_multiprocessing`
+    /// out of `sys.modules`, `false` also turns `os.system`/`os.fork`/
+    /// `os.exec*` into a `PermissionError`, since those live in `os`/`posix`/
+    /// `nt`, which importlib depends on and which are therefore always
+    /// registered.
+    pub fn allow_subprocess(mut self, allow: bool) -> Self {
+        self.allow_subprocess = allow;
+        self
+    }
+
+    #[cfg(feature = "stdlib")]
+    fn denied_modules(&self) -> Vec<&'static str> {
+        let mut denied = Vec::new();
+        if !self.allow_filesystem {
+            denied.push("mmap");
+        }
+        if !self.allow_network {
+            denied.extend(["_socket", "_net", "select", "_ssl"]);
+        }
+        if !self.allow_subprocess {
+            denied.extend(["_posixsubprocess", "_multiprocessing"]);
+        }
+        denied
+    }
+
     #[cfg(feature = "stdlib")]
     pub fn init_stdlib(self) -> Self {
-        self.init_hook(Box::new(init_stdlib))
+        let denied = self.denied_modules();
+        let allow_subprocess = self.allow_subprocess;
+        let allow_filesystem = self.allow_filesystem;
+        self.init_hook(Box::new(move |vm| {
+            init_stdlib(vm, &denied);
+            vm.state
+                .subprocess_allowed
+                .store(allow_subprocess, std::sync::atomic::Ordering::Relaxed);
+            vm.state
+                .filesystem_allowed
+                .store(allow_filesystem, std::sync::atomic::Ordering::Relaxed);
+        }))
     }
 }
 
 #[cfg(feature = "stdlib")]
-pub fn init_stdlib(vm: &mut VirtualMachine) {
-    vm.add_native_modules(rustpython_stdlib::get_module_inits());
+pub fn init_stdlib(vm: &mut VirtualMachine, denied_modules: &[&'static str]) {
+    vm.add_native_modules(
+        rustpython_stdlib::get_module_inits()
+            .filter(|(name, _)| !denied_modules.contains(&name.as_ref())),
+    );
 
     // if we're on freeze-stdlib, the core stdlib modules will be included anyway
     #[cfg(feature = "freeze-stdlib")]
@@ -260,6 +260,9 @@ pub fn parse_opts() -> Result<(Settings, RunMode), lexopt::Error> {
 
     settings.check_hash_pycs_mode = args.check_hash_based_pycs;
 
+    settings.pycache_prefix =
+        get_env("PYTHONPYCACHEPREFIX").map(|val| val.to_string_lossy().into_owned());
+
     let xopts = args.implementation_option.into_iter().map(|s| {
         let (name, value) = match s.split_once('=') {
             Some((name, value)) => (name.to_owned(), Some(value)),
@@ -502,6 +502,11 @@ mod array {
         (Double, f64, 'd', "d"),
     );
 
+    /// A string with all available type codes, same order CPython's `array`
+    /// module exposes them in.
+    #[pyattr(name = "typecodes")]
+    const TYPECODES: &str = "bBuhHiIlLqQfd";
+
     #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
     pub struct WideChar(wchar_t);
 
@@ -0,0 +1,77 @@
+//! `_continuation`: a greenlet-style *one-shot* continuation built on top of
+//! an existing generator's suspended frame. Unlike a real greenlet, a
+//! `Continuation` can be resumed exactly once -- it consumes itself on
+//! `resume()` -- which keeps it safe to implement without stack-switching:
+//! under the hood it's just a thin wrapper around [`PyGenerator`]'s own
+//! `send`, with a one-shot guard on top.
+
+pub(crate) use _continuation::make_module;
+
+#[pymodule]
+mod _continuation {
+    use crate::vm::{
+        AsObject, PyObjectRef, PyPayload, PyResult, VirtualMachine,
+        builtins::PyGenerator,
+        class::StaticType,
+        protocol::PyIterReturn,
+        types::Constructor,
+    };
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[pyattr]
+    #[pyclass(name = "Continuation")]
+    #[derive(Debug, PyPayload)]
+    pub struct PyContinuation {
+        generator: PyObjectRef,
+        consumed: AtomicBool,
+    }
+
+    impl Constructor for PyContinuation {
+        type Args = PyObjectRef;
+
+        fn py_new(
+            cls: crate::vm::builtins::PyTypeRef,
+            generator: Self::Args,
+            vm: &VirtualMachine,
+        ) -> PyResult {
+            if !generator.fast_isinstance(PyGenerator::class(&vm.ctx)) {
+                return Err(vm.new_type_error("Continuation requires a generator object"));
+            }
+            Ok(Self {
+                generator,
+                consumed: AtomicBool::new(false),
+            }
+            .into_ref_with_type(vm, cls)?
+            .into())
+        }
+    }
+
+    #[pyclass(with(Constructor))]
+    impl PyContinuation {
+        /// Resume the captured frame exactly once, sending `value` in as the
+        /// result of the `yield` expression it's suspended at. Calling this
+        /// a second time raises `RuntimeError`, just like re-entering an
+        /// already-exhausted greenlet would.
+        #[pymethod]
+        fn resume(&self, value: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+            if self.consumed.swap(true, Ordering::SeqCst) {
+                return Err(vm.new_runtime_error("continuation already resumed"));
+            }
+            let gen = self
+                .generator
+                .downcast_ref::<PyGenerator>()
+                .ok_or_else(|| vm.new_type_error("Continuation requires a generator object"))?;
+            match gen.as_coro().send(&self.generator, value, vm)? {
+                PyIterReturn::Return(value) => Ok(value),
+                PyIterReturn::StopIteration(value) => {
+                    Ok(value.unwrap_or_else(|| vm.ctx.none()))
+                }
+            }
+        }
+
+        #[pygetset]
+        fn consumed(&self) -> bool {
+            self.consumed.load(Ordering::SeqCst)
+        }
+    }
+}
@@ -401,7 +401,7 @@ mod _csv {
             write,
             state: PyMutex::new(WriteState {
                 buffer: vec![0; 1024],
-                writer: options.to_writer(),
+                writer: options.to_writer(vm)?,
             }),
             dialect: options.result(vm)?,
         })
@@ -423,16 +423,26 @@ mod _csv {
         Strings = 4,
         Notnull = 5,
     }
-    impl From<QuoteStyle> for csv_core::QuoteStyle {
-        fn from(val: QuoteStyle) -> Self {
-            match val {
-                QuoteStyle::Minimal => Self::Always,
-                QuoteStyle::All => Self::Always,
-                QuoteStyle::Nonnumeric => Self::NonNumeric,
-                QuoteStyle::None => Self::Never,
-                QuoteStyle::Strings => todo!(),
-                QuoteStyle::Notnull => todo!(),
-            }
+    impl QuoteStyle {
+        /// Maps to the `csv_core` writer's quoting mode. `Strings` and
+        /// `Notnull` have no equivalent here: unlike the reader (which
+        /// decides per field from the original `str`/`None`-ness of each
+        /// Python value, see `Reader::__next__`), `csv_core::Writer` only
+        /// sees already-stringified bytes and applies one static style to
+        /// the whole writer, so there's no way to recover "was this field a
+        /// `str`" or "was this field `None`" by the time it gets here.
+        fn to_csv_core(self, vm: &VirtualMachine) -> PyResult<csv_core::QuoteStyle> {
+            Ok(match self {
+                Self::Minimal => csv_core::QuoteStyle::Always,
+                Self::All => csv_core::QuoteStyle::Always,
+                Self::Nonnumeric => csv_core::QuoteStyle::NonNumeric,
+                Self::None => csv_core::QuoteStyle::Never,
+                Self::Strings | Self::Notnull => {
+                    return Err(vm.new_not_implemented_error(format!(
+                        "csv writer does not support quoting={self:?} yet (only the reader does)"
+                    )));
+                }
+            })
         }
     }
     impl TryFromObject for QuoteStyle {
@@ -825,7 +835,7 @@ mod _csv {
             };
             reader.build()
         }
-        fn to_writer(&self) -> csv_core::Writer {
+        fn to_writer(&self, vm: &VirtualMachine) -> PyResult<csv_core::Writer> {
             let mut builder = csv_core::WriterBuilder::new();
             let mut writer = match &self.dialect {
                 DialectItem::Str(name) => {
@@ -879,9 +889,9 @@ mod _csv {
                 writer = writer.escape(e);
             }
             if let Some(e) = self.quoting {
-                writer = writer.quote_style(e.into());
+                writer = writer.quote_style(e.to_csv_core(vm)?);
             }
-            writer.build()
+            Ok(writer.build())
         }
     }
 
@@ -922,6 +932,37 @@ mod _csv {
         }
     }
     impl SelfIter for Reader {}
+
+    /// `csv_core::Reader::read_record` dequotes fields as it scans them, so
+    /// its output buffer no longer distinguishes a quoted field from an
+    /// unquoted one -- but `QUOTE_NONNUMERIC`/`QUOTE_STRINGS`/`QUOTE_NOTNULL`
+    /// all need exactly that bit per field. Re-walk the same raw record
+    /// bytes the reader was just given, using the same quote/delimiter
+    /// rules, purely to recover it.
+    fn field_quoted_flags(record: &[u8], delimiter: u8, quotechar: Option<u8>) -> Vec<bool> {
+        let Some(quotechar) = quotechar else {
+            return vec![false; record.iter().filter(|&&b| b == delimiter).count() + 1];
+        };
+        let mut flags = Vec::new();
+        let mut at_field_start = true;
+        let mut in_quotes = false;
+        for &b in record {
+            if at_field_start && !in_quotes {
+                flags.push(b == quotechar);
+                at_field_start = false;
+            }
+            if b == quotechar {
+                in_quotes = !in_quotes;
+            } else if b == delimiter && !in_quotes {
+                at_field_start = true;
+            }
+        }
+        if at_field_start {
+            flags.push(false);
+        }
+        flags
+    }
+
     impl IterNext for Reader {
         fn next(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<PyIterReturn> {
             let string = raise_if_stop!(zelf.iter.next(vm)?);
@@ -1001,10 +1042,16 @@ mod _csv {
                 ));
             }
 
+            let quoted = field_quoted_flags(
+                &input.as_bytes()[..input_offset],
+                *delimiter,
+                zelf.dialect.quotechar,
+            );
             let mut prev_end = 0;
             let out: Vec<PyObjectRef> = output_ends[..output_ends_offset]
                 .iter()
-                .map(|&end| {
+                .enumerate()
+                .map(|(i, &end)| {
                     let range = prev_end..end;
                     if range.len() > field_limit as usize {
                         return Err(new_csv_error(vm, "filed too long to read".to_string()));
@@ -1013,18 +1060,41 @@ mod _csv {
                     let s = std::str::from_utf8(&buffer[range.clone()])
                         // not sure if this is possible - the input was all strings
                         .map_err(|_e| vm.new_unicode_decode_error("csv not utf8"))?;
-                    // Rustpython TODO!
-                    // Incomplete implementation
-                    if let QuoteStyle::Nonnumeric = zelf.dialect.quoting {
-                        if let Ok(t) =
-                            String::from_utf8(trim_spaces(&buffer[range.clone()]).to_vec())
-                                .unwrap()
-                                .parse::<i64>()
-                        {
-                            Ok(vm.ctx.new_int(t).into())
+                    let is_quoted = quoted.get(i).copied().unwrap_or(false);
+                    // `csv.QUOTE_NONNUMERIC`/`csv.QUOTE_STRINGS` convert every
+                    // unquoted, non-empty field to a float (raising `ValueError`
+                    // if it isn't one), same as real CPython's reader; quoted
+                    // fields are left as strings. An unquoted field that is
+                    // empty or only spaces becomes `None` under
+                    // `csv.QUOTE_STRINGS` and `csv.QUOTE_NOTNULL` (which never
+                    // does numeric conversion), and stays a string otherwise.
+                    let numeric = matches!(
+                        zelf.dialect.quoting,
+                        QuoteStyle::Nonnumeric | QuoteStyle::Strings
+                    ) && !is_quoted;
+                    let null = matches!(
+                        zelf.dialect.quoting,
+                        QuoteStyle::Notnull | QuoteStyle::Strings
+                    ) && !is_quoted;
+                    let trimmed = trim_spaces(&buffer[range]);
+                    if trimmed.is_empty() {
+                        if null {
+                            Ok(vm.ctx.none())
                         } else {
                             Ok(vm.ctx.new_str(s).into())
                         }
+                    } else if numeric {
+                        let trimmed = std::str::from_utf8(trimmed)
+                            .map_err(|_e| vm.new_unicode_decode_error("csv not utf8"))?;
+                        trimmed
+                            .trim()
+                            .parse::<f64>()
+                            .map(|f| vm.ctx.new_float(f).into())
+                            .map_err(|_e| {
+                                vm.new_value_error(format!(
+                                    "could not convert string to float: '{s}'"
+                                ))
+                            })
                     } else {
                         Ok(vm.ctx.new_str(s).into())
                     }
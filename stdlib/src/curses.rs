@@ -0,0 +1,231 @@
+// spell-checker:disable
+
+pub(crate) use decl::make_module;
+
+#[pymodule(name = "_curses")]
+mod decl {
+    use crate::vm::{
+        PyPayload, PyResult, VirtualMachine,
+        builtins::{PyStrRef, PyTypeRef},
+        function::ArgIntoBool,
+    };
+    use ncurses::WINDOW;
+
+    #[pyattr(name = "error", once)]
+    fn error_type(vm: &VirtualMachine) -> PyTypeRef {
+        vm.ctx.new_exception_type(
+            "_curses",
+            "error",
+            Some(vec![vm.ctx.exceptions.exception_type.to_owned()]),
+        )
+    }
+
+    fn curses_error(vm: &VirtualMachine, msg: impl Into<String>) -> crate::vm::PyBaseExceptionRef {
+        vm.new_exception_msg(error_type(vm), msg.into())
+    }
+
+    fn check(vm: &VirtualMachine, ret: i32, what: &str) -> PyResult<()> {
+        if ret == ncurses::ERR {
+            Err(curses_error(vm, format!("{what}() returned ERR")))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[pyattr]
+    use ncurses::{
+        A_BOLD, A_NORMAL, A_REVERSE, A_STANDOUT, A_UNDERLINE, COLOR_BLACK, COLOR_BLUE, COLOR_CYAN,
+        COLOR_GREEN, COLOR_MAGENTA, COLOR_RED, COLOR_WHITE, COLOR_YELLOW, KEY_BACKSPACE,
+        KEY_DOWN, KEY_ENTER, KEY_HOME, KEY_LEFT, KEY_NPAGE, KEY_PPAGE, KEY_RESIZE, KEY_RIGHT,
+        KEY_UP,
+    };
+
+    #[pyattr]
+    #[pyclass(module = "_curses", name = "window")]
+    #[derive(PyPayload)]
+    struct PyCursesWindow {
+        // raw ncurses WINDOW* handle; ncurses itself is not thread-safe, and Python scripts
+        // using this module are expected to drive it from a single thread, same as CPython's.
+        win: WINDOW,
+    }
+
+    impl std::fmt::Debug for PyCursesWindow {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("PyCursesWindow").finish()
+        }
+    }
+
+    unsafe impl Send for PyCursesWindow {}
+    unsafe impl Sync for PyCursesWindow {}
+
+    #[pyclass]
+    impl PyCursesWindow {
+        #[pymethod]
+        fn addstr(&self, s: PyStrRef, vm: &VirtualMachine) -> PyResult<()> {
+            check(vm, ncurses::waddstr(self.win, s.as_str()), "addstr")
+        }
+
+        #[pymethod]
+        fn mvaddstr(&self, y: i32, x: i32, s: PyStrRef, vm: &VirtualMachine) -> PyResult<()> {
+            check(
+                vm,
+                ncurses::mvwaddstr(self.win, y, x, s.as_str()),
+                "mvaddstr",
+            )
+        }
+
+        #[pymethod]
+        fn getch(&self) -> i32 {
+            ncurses::wgetch(self.win)
+        }
+
+        #[pymethod]
+        fn r#move(&self, y: i32, x: i32, vm: &VirtualMachine) -> PyResult<()> {
+            check(vm, ncurses::wmove(self.win, y, x), "move")
+        }
+
+        #[pymethod]
+        fn refresh(&self, vm: &VirtualMachine) -> PyResult<()> {
+            check(vm, ncurses::wrefresh(self.win), "refresh")
+        }
+
+        #[pymethod]
+        fn clear(&self, vm: &VirtualMachine) -> PyResult<()> {
+            check(vm, ncurses::wclear(self.win), "clear")
+        }
+
+        #[pymethod]
+        fn erase(&self, vm: &VirtualMachine) -> PyResult<()> {
+            check(vm, ncurses::werase(self.win), "erase")
+        }
+
+        #[pymethod]
+        fn keypad(&self, on: ArgIntoBool, vm: &VirtualMachine) -> PyResult<()> {
+            check(vm, ncurses::keypad(self.win, *on), "keypad")
+        }
+
+        #[pymethod]
+        fn nodelay(&self, on: ArgIntoBool, vm: &VirtualMachine) -> PyResult<()> {
+            check(vm, ncurses::nodelay(self.win, *on), "nodelay")
+        }
+
+        #[pymethod]
+        fn timeout(&self, delay: i32) {
+            ncurses::wtimeout(self.win, delay);
+        }
+
+        #[pymethod]
+        fn getmaxyx(&self) -> (i32, i32) {
+            (ncurses::getmaxy(self.win), ncurses::getmaxx(self.win))
+        }
+    }
+
+    #[pyfunction]
+    fn initscr(vm: &VirtualMachine) -> PyResult<PyCursesWindow> {
+        let win = ncurses::initscr();
+        if win.is_null() {
+            return Err(curses_error(vm, "initscr() returned ERR"));
+        }
+        Ok(PyCursesWindow { win })
+    }
+
+    #[pyfunction]
+    fn endwin(vm: &VirtualMachine) -> PyResult<()> {
+        check(vm, ncurses::endwin(), "endwin")
+    }
+
+    #[pyfunction]
+    fn isendwin() -> bool {
+        ncurses::isendwin()
+    }
+
+    #[pyfunction]
+    fn doupdate(vm: &VirtualMachine) -> PyResult<()> {
+        check(vm, ncurses::doupdate(), "doupdate")
+    }
+
+    #[pyfunction]
+    fn cbreak(vm: &VirtualMachine) -> PyResult<()> {
+        check(vm, ncurses::cbreak(), "cbreak")
+    }
+
+    #[pyfunction]
+    fn nocbreak(vm: &VirtualMachine) -> PyResult<()> {
+        check(vm, ncurses::nocbreak(), "nocbreak")
+    }
+
+    #[pyfunction]
+    fn echo(vm: &VirtualMachine) -> PyResult<()> {
+        check(vm, ncurses::echo(), "echo")
+    }
+
+    #[pyfunction]
+    fn noecho(vm: &VirtualMachine) -> PyResult<()> {
+        check(vm, ncurses::noecho(), "noecho")
+    }
+
+    #[pyfunction]
+    fn curs_set(visibility: i32, vm: &VirtualMachine) -> PyResult<i32> {
+        let prev = ncurses::curs_set(visibility);
+        if prev == ncurses::ERR {
+            Err(curses_error(vm, "curs_set() returned ERR"))
+        } else {
+            Ok(prev)
+        }
+    }
+
+    #[pyfunction]
+    fn start_color(vm: &VirtualMachine) -> PyResult<()> {
+        check(vm, ncurses::start_color(), "start_color")
+    }
+
+    #[pyfunction]
+    fn has_colors() -> bool {
+        ncurses::has_colors()
+    }
+
+    #[pyfunction]
+    fn init_pair(pair_number: i16, fg: i16, bg: i16, vm: &VirtualMachine) -> PyResult<()> {
+        check(vm, ncurses::init_pair(pair_number, fg, bg), "init_pair")
+    }
+
+    #[pyfunction]
+    fn color_pair(pair_number: i16) -> i32 {
+        ncurses::COLOR_PAIR(pair_number)
+    }
+
+    #[pyfunction]
+    fn napms(ms: i32, vm: &VirtualMachine) -> PyResult<()> {
+        check(vm, ncurses::napms(ms), "napms")
+    }
+
+    #[pyfunction]
+    fn beep(vm: &VirtualMachine) -> PyResult<()> {
+        check(vm, ncurses::beep(), "beep")
+    }
+
+    #[pyfunction]
+    fn flash(vm: &VirtualMachine) -> PyResult<()> {
+        check(vm, ncurses::flash(), "flash")
+    }
+
+    #[pyfunction]
+    fn flushinp() {
+        ncurses::flushinp();
+    }
+
+    #[pyfunction]
+    fn newwin(
+        nlines: i32,
+        ncols: i32,
+        begin_y: i32,
+        begin_x: i32,
+        vm: &VirtualMachine,
+    ) -> PyResult<PyCursesWindow> {
+        let win = ncurses::newwin(nlines, ncols, begin_y, begin_x);
+        if win.is_null() {
+            return Err(curses_error(vm, "newwin() returned ERR"));
+        }
+        Ok(PyCursesWindow { win })
+    }
+}
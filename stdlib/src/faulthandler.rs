@@ -2,7 +2,14 @@ pub(crate) use decl::make_module;
 
 #[pymodule(name = "faulthandler")]
 mod decl {
-    use crate::vm::{VirtualMachine, frame::Frame, function::OptionalArg, stdlib::sys::PyStderr};
+    use crate::vm::{
+        PyObjectRef, PyResult, VirtualMachine,
+        convert::TryFromObject,
+        frame::Frame,
+        function::OptionalArg,
+        stdlib::sys::PyStderr,
+    };
+    use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
 
     fn dump_frame(frame: &Frame, vm: &VirtualMachine) {
         let stderr = PyStderr(vm);
@@ -29,35 +36,286 @@ mod decl {
         }
     }
 
+    /// Turn a `file` argument (a raw fd, or anything with a `fileno()` method, or unset
+    /// meaning "whatever `sys.stderr` is right now") into a raw fd, the only thing that's
+    /// safe to write to from code that might run with the interpreter in a bad state.
+    fn resolve_fd(file: OptionalArg<PyObjectRef>, vm: &VirtualMachine) -> PyResult<i32> {
+        let obj = match file.into_option() {
+            Some(obj) => obj,
+            None => vm.sys_module.get_attr("stderr", vm)?,
+        };
+        if let Ok(fd) = i32::try_from_object(vm, obj.clone()) {
+            return Ok(fd);
+        }
+        let fileno = obj.get_attr("fileno", vm)?.call((), vm)?;
+        i32::try_from_object(vm, fileno)
+    }
+
+    // fd 2 is stderr on every platform this interpreter runs on.
+    const STDERR_FD: i32 = 2;
+
+    static ENABLED: AtomicBool = AtomicBool::new(false);
+    static FAULT_FD: AtomicI32 = AtomicI32::new(STDERR_FD);
+
     #[derive(FromArgs)]
-    #[allow(unused)]
     struct EnableArgs {
         #[pyarg(any, default)]
-        file: Option<i64>,
+        file: OptionalArg<PyObjectRef>,
         #[pyarg(any, default = true)]
         all_threads: bool,
     }
 
     #[pyfunction]
-    const fn enable(_args: EnableArgs) {
-        // TODO
+    fn enable(args: EnableArgs, vm: &VirtualMachine) -> PyResult<()> {
+        let fd = resolve_fd(args.file, vm)?;
+        FAULT_FD.store(fd, Ordering::Relaxed);
+        let _ = args.all_threads; // accepted for API compatibility; `dump_traceback_to_fd` only dumps the thread handling the signal
+        fatal::install();
+        ENABLED.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    #[pyfunction]
+    fn disable() -> bool {
+        let was_enabled = ENABLED.swap(false, Ordering::Relaxed);
+        fatal::restore();
+        was_enabled
+    }
+
+    #[pyfunction]
+    fn is_enabled() -> bool {
+        ENABLED.load(Ordering::Relaxed)
     }
 
     #[derive(FromArgs)]
-    #[allow(unused)]
     struct RegisterArgs {
         #[pyarg(positional)]
-        signum: i64,
+        signum: i32,
         #[pyarg(any, default)]
-        file: Option<i64>,
+        file: OptionalArg<PyObjectRef>,
         #[pyarg(any, default = true)]
         all_threads: bool,
         #[pyarg(any, default = false)]
         chain: bool,
     }
 
+    #[cfg(unix)]
+    #[pyfunction]
+    fn register(args: RegisterArgs, vm: &VirtualMachine) -> PyResult<()> {
+        let fd = resolve_fd(args.file, vm)?;
+        let _ = args.all_threads; // see `dump_traceback_to_fd`: only the thread handling the signal is dumped
+        fatal::register(args.signum, fd, args.chain, vm)
+    }
+
+    #[cfg(not(unix))]
+    #[pyfunction]
+    fn register(args: RegisterArgs, vm: &VirtualMachine) -> PyResult<()> {
+        let _ = (args, vm);
+        Err(vm.new_not_implemented_error("faulthandler.register is only available on unix"))
+    }
+
+    #[cfg(unix)]
+    #[pyfunction]
+    fn unregister(signum: i32, vm: &VirtualMachine) -> PyResult<bool> {
+        fatal::unregister(signum, vm)
+    }
+
+    #[cfg(not(unix))]
+    #[pyfunction]
+    fn unregister(signum: i32, vm: &VirtualMachine) -> PyResult<bool> {
+        let _ = signum;
+        Err(vm.new_not_implemented_error("faulthandler.unregister is only available on unix"))
+    }
+
+    #[derive(FromArgs)]
+    struct DumpTracebackLaterArgs {
+        #[pyarg(positional)]
+        timeout: f64,
+        #[pyarg(any, default = false)]
+        repeat: bool,
+        #[pyarg(any, default)]
+        file: OptionalArg<PyObjectRef>,
+        #[pyarg(any, default = false)]
+        exit: bool,
+    }
+
+    // bumped every time dump_traceback_later/cancel_dump_traceback_later runs, so a stale
+    // watchdog thread from an earlier call notices it's been superseded and gives up instead
+    // of dumping (or exiting the process!) on its own schedule.
+    static WATCHDOG_GENERATION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+    #[pyfunction]
+    fn dump_traceback_later(args: DumpTracebackLaterArgs, vm: &VirtualMachine) -> PyResult<()> {
+        if args.timeout <= 0.0 {
+            return Err(vm.new_value_error("timeout must be greater than 0"));
+        }
+        let fd = resolve_fd(args.file, vm)?;
+        let generation = WATCHDOG_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+        let dur = std::time::Duration::from_secs_f64(args.timeout);
+        let repeat = args.repeat;
+        let exit = args.exit;
+        // SAFETY: the watchdog thread only dereferences this pointer while `generation` is
+        // still current, i.e. before `cancel_dump_traceback_later`/another `dump_traceback_later`
+        // call bumps `WATCHDOG_GENERATION` past it. As in CPython's own faulthandler, the
+        // embedder is responsible for cancelling any pending watchdog before tearing down the
+        // interpreter that `vm` belongs to.
+        let vm_addr = vm as *const VirtualMachine as usize;
+        std::thread::spawn(move || {
+            loop {
+                std::thread::sleep(dur);
+                if WATCHDOG_GENERATION.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+                let vm = unsafe { &*(vm_addr as *const VirtualMachine) };
+                dump_traceback_to_fd(fd, vm);
+                if exit {
+                    std::process::exit(1);
+                }
+                if !repeat {
+                    return;
+                }
+            }
+        });
+        Ok(())
+    }
+
     #[pyfunction]
-    const fn register(_args: RegisterArgs) {
-        // TODO
+    fn cancel_dump_traceback_later() {
+        WATCHDOG_GENERATION.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn dump_traceback_to_fd(fd: i32, vm: &VirtualMachine) {
+        fatal::raw_write(fd, "Stack (most recent call first):\n");
+        match vm.frames.try_borrow() {
+            Ok(frames) => {
+                for frame in frames.iter() {
+                    let line = format!(
+                        "  File \"{}\", line {} in {}\n",
+                        frame.code.source_path,
+                        frame.current_location().row,
+                        frame.code.obj_name
+                    );
+                    fatal::raw_write(fd, &line);
+                }
+            }
+            Err(_) => fatal::raw_write(fd, "  <traceback unavailable: frame stack busy>\n"),
+        }
+    }
+
+    /// Signal-handler-side plumbing. Split out because a real signal handler can only safely
+    /// call a very small set of functions (no allocation through the normal Python I/O stack,
+    /// no locking that could already be held), so everything here sticks to raw `write(2)`
+    /// and best-effort, panic-contained access to the interpreter running on the same thread.
+    #[cfg(unix)]
+    mod fatal {
+        use super::dump_traceback_to_fd;
+        use crate::vm::{PyResult, VirtualMachine};
+        use std::sync::atomic::{AtomicBool, AtomicI32, AtomicUsize, Ordering};
+
+        pub(super) fn raw_write(fd: i32, s: &str) {
+            unsafe { libc::write(fd, s.as_ptr() as *const _, s.len()) };
+        }
+
+        const FATAL_SIGNALS: &[i32] = &[
+            libc::SIGSEGV,
+            libc::SIGABRT,
+            libc::SIGBUS,
+            libc::SIGILL,
+            libc::SIGFPE,
+        ];
+
+        extern "C" fn fatal_handler(signum: i32) {
+            let fd = super::FAULT_FD.load(Ordering::Relaxed);
+            raw_write(fd, &format!("Fatal Python error: signal {signum}\n\n"));
+            // a fault mid-dump would just re-enter this same handler and get SIG_DFL'd below,
+            // so there's nothing further to contain here beyond the try_borrow in the dump itself.
+            crate::vm::vm::thread::try_with_current_vm(|vm| dump_traceback_to_fd(fd, vm));
+            unsafe {
+                libc::signal(signum, libc::SIG_DFL);
+                libc::raise(signum);
+            }
+        }
+
+        pub(super) fn install() {
+            for &signum in FATAL_SIGNALS {
+                unsafe { libc::signal(signum, fatal_handler as libc::sighandler_t) };
+            }
+        }
+
+        pub(super) fn restore() {
+            for &signum in FATAL_SIGNALS {
+                unsafe { libc::signal(signum, libc::SIG_DFL) };
+            }
+        }
+
+        // arbitrary but generous; matches `_signal.NSIG` elsewhere in the interpreter.
+        const MAX_SIGNUM: usize = 64;
+
+        #[allow(clippy::declare_interior_mutable_const)]
+        const OLD_HANDLER_UNSET: AtomicUsize = AtomicUsize::new(0);
+        #[allow(clippy::declare_interior_mutable_const)]
+        const REGISTERED_FD_DEFAULT: AtomicI32 = AtomicI32::new(super::STDERR_FD);
+        #[allow(clippy::declare_interior_mutable_const)]
+        const CHAIN_DEFAULT: AtomicBool = AtomicBool::new(false);
+
+        static REGISTERED_OLD: [AtomicUsize; MAX_SIGNUM] = [OLD_HANDLER_UNSET; MAX_SIGNUM];
+        static REGISTERED_FD: [AtomicI32; MAX_SIGNUM] = [REGISTERED_FD_DEFAULT; MAX_SIGNUM];
+        static REGISTERED_CHAIN: [AtomicBool; MAX_SIGNUM] = [CHAIN_DEFAULT; MAX_SIGNUM];
+
+        extern "C" fn registered_handler(signum: i32) {
+            let idx = signum as usize;
+            let fd = REGISTERED_FD[idx].load(Ordering::Relaxed);
+            crate::vm::vm::thread::try_with_current_vm(|vm| dump_traceback_to_fd(fd, vm));
+            if REGISTERED_CHAIN[idx].load(Ordering::Relaxed) {
+                let old = REGISTERED_OLD[idx].load(Ordering::Relaxed);
+                if old != 0 && old != libc::SIG_DFL as usize && old != libc::SIG_IGN as usize {
+                    let f: extern "C" fn(i32) = unsafe { std::mem::transmute(old) };
+                    f(signum);
+                }
+            }
+        }
+
+        fn check_signum(signum: i32, vm: &VirtualMachine) -> PyResult<usize> {
+            if signum <= 0 || signum as usize >= MAX_SIGNUM {
+                Err(vm.new_value_error("signal number out of range"))
+            } else {
+                Ok(signum as usize)
+            }
+        }
+
+        pub(super) fn register(signum: i32, fd: i32, chain: bool, vm: &VirtualMachine) -> PyResult<()> {
+            let idx = check_signum(signum, vm)?;
+            REGISTERED_FD[idx].store(fd, Ordering::Relaxed);
+            REGISTERED_CHAIN[idx].store(chain, Ordering::Relaxed);
+            let old = unsafe { libc::signal(signum, registered_handler as libc::sighandler_t) };
+            if old != libc::SIG_ERR {
+                REGISTERED_OLD[idx].store(old as usize, Ordering::Relaxed);
+            }
+            Ok(())
+        }
+
+        pub(super) fn unregister(signum: i32, vm: &VirtualMachine) -> PyResult<bool> {
+            let idx = check_signum(signum, vm)?;
+            let old = REGISTERED_OLD[idx].swap(0, Ordering::Relaxed);
+            if old == 0 {
+                return Ok(false);
+            }
+            unsafe { libc::signal(signum, old as libc::sighandler_t) };
+            Ok(true)
+        }
+    }
+
+    #[cfg(not(unix))]
+    mod fatal {
+        pub(super) fn raw_write(fd: i32, s: &str) {
+            use std::io::Write;
+            // best-effort outside unix: there's no signal-safe write primitive to reach for,
+            // so just use the libc wrapper the rest of std is already built on.
+            let _ = std::io::stderr().write_all(s.as_bytes());
+            let _ = fd;
+        }
+
+        pub(super) fn install() {}
+        pub(super) fn restore() {}
     }
 }
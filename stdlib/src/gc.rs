@@ -2,31 +2,63 @@ pub(crate) use gc::make_module;
 
 #[pymodule]
 mod gc {
-    use crate::vm::{PyResult, VirtualMachine, function::FuncArgs};
+    use crate::vm::{
+        AsObject, PyObjectRef, PyResult, VirtualMachine,
+        function::FuncArgs,
+        gc::{cyclic_objects, direct_referents, live_roots, reachable},
+    };
+    use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+
+    /// The flag set by `enable()`/`disable()` and reported by `isenabled()`.
+    /// RustPython objects are reference-counted (see
+    /// [`crate::vm::object::Traverse`]), so there is no allocator-driven
+    /// trigger to disable here, and the explicit `collect()` call below
+    /// runs whether or not this flag is set.
+    static ENABLED: AtomicBool = AtomicBool::new(true);
+    static COLLECTIONS: AtomicI64 = AtomicI64::new(0);
 
     #[pyfunction]
-    fn collect(_args: FuncArgs, _vm: &VirtualMachine) -> i32 {
-        0
+    fn collect(_args: FuncArgs, vm: &VirtualMachine) -> PyResult<i32> {
+        let found = cyclic_objects(vm);
+        COLLECTIONS.fetch_add(1, Ordering::Relaxed);
+        // In a debug build, a collection pass doubles as a heap
+        // consistency check: see `vm::gc::verify_heap_consistency` for
+        // what "consistency" means here. This is the always-on half of
+        // the GC stress/verification mode; the other half, re-checking
+        // after every single instruction, is opt-in behind the
+        // `gc-stress` build feature since it is far too slow to run by
+        // default (see `vm::gc::stress_check`).
+        #[cfg(debug_assertions)]
+        if let Some(violation) = crate::vm::gc::verify_heap_consistency(vm)
+            .into_iter()
+            .next()
+        {
+            return Err(
+                vm.new_system_error(format!("gc: heap consistency check failed: {violation}"))
+            );
+        }
+        Ok(found.len() as i32)
     }
 
     #[pyfunction]
     fn isenabled(_args: FuncArgs, _vm: &VirtualMachine) -> bool {
-        false
+        ENABLED.load(Ordering::Relaxed)
     }
 
     #[pyfunction]
-    fn enable(_args: FuncArgs, vm: &VirtualMachine) -> PyResult {
-        Err(vm.new_not_implemented_error(""))
+    fn enable(_args: FuncArgs, _vm: &VirtualMachine) {
+        ENABLED.store(true, Ordering::Relaxed);
     }
 
     #[pyfunction]
-    fn disable(_args: FuncArgs, vm: &VirtualMachine) -> PyResult {
-        Err(vm.new_not_implemented_error(""))
+    fn disable(_args: FuncArgs, _vm: &VirtualMachine) {
+        ENABLED.store(false, Ordering::Relaxed);
     }
 
     #[pyfunction]
-    fn get_count(_args: FuncArgs, vm: &VirtualMachine) -> PyResult {
-        Err(vm.new_not_implemented_error(""))
+    fn get_count(_args: FuncArgs, vm: &VirtualMachine) -> (i32, i32, i32) {
+        let _ = vm;
+        (COLLECTIONS.load(Ordering::Relaxed) as i32, 0, 0)
     }
 
     #[pyfunction]
@@ -34,19 +66,36 @@ mod gc {
         Err(vm.new_not_implemented_error(""))
     }
 
+    /// Returns every object gc currently knows about: everything reachable
+    /// from a live frame (see the scope note on [`live_roots`]). Unlike
+    /// CPython, this is not backed by a separate allocation-time registry,
+    /// so an object that's alive but unreachable from any running frame
+    /// (e.g. held only by a Rust-side cache) won't appear here.
     #[pyfunction]
-    fn get_objects(_args: FuncArgs, vm: &VirtualMachine) -> PyResult {
-        Err(vm.new_not_implemented_error(""))
+    fn get_objects(_args: FuncArgs, vm: &VirtualMachine) -> Vec<PyObjectRef> {
+        reachable(live_roots(vm)).into_values().collect()
     }
 
     #[pyfunction]
-    fn get_referents(_args: FuncArgs, vm: &VirtualMachine) -> PyResult {
-        Err(vm.new_not_implemented_error(""))
+    fn get_referents(args: FuncArgs, _vm: &VirtualMachine) -> Vec<PyObjectRef> {
+        args.args
+            .iter()
+            .flat_map(|obj| direct_referents(obj.as_object()))
+            .collect()
     }
 
     #[pyfunction]
-    fn get_referrers(_args: FuncArgs, vm: &VirtualMachine) -> PyResult {
-        Err(vm.new_not_implemented_error(""))
+    fn get_referrers(args: FuncArgs, vm: &VirtualMachine) -> Vec<PyObjectRef> {
+        let targets: std::collections::HashSet<usize> =
+            args.args.iter().map(|obj| obj.get_id()).collect();
+        reachable(live_roots(vm))
+            .into_values()
+            .filter(|candidate| {
+                direct_referents(candidate.as_object())
+                    .iter()
+                    .any(|child| targets.contains(&child.get_id()))
+            })
+            .collect()
     }
 
     #[pyfunction]
@@ -55,8 +104,8 @@ mod gc {
     }
 
     #[pyfunction]
-    fn get_threshold(_args: FuncArgs, vm: &VirtualMachine) -> PyResult {
-        Err(vm.new_not_implemented_error(""))
+    fn get_threshold(_args: FuncArgs, _vm: &VirtualMachine) -> (i32, i32, i32) {
+        (700, 10, 10)
     }
 
     #[pyfunction]
@@ -70,7 +119,7 @@ mod gc {
     }
 
     #[pyfunction]
-    fn set_threshold(_args: FuncArgs, vm: &VirtualMachine) -> PyResult {
-        Err(vm.new_not_implemented_error(""))
+    fn set_threshold(_args: FuncArgs, _vm: &VirtualMachine) {
+        // thresholds are a no-op placeholder until generational collection lands
     }
 }
@@ -14,7 +14,7 @@ pub mod _hashlib {
         types::Representable,
     };
     use blake2::{Blake2b512, Blake2s256};
-    use digest::{DynDigest, core_api::BlockSizeUser};
+    use digest::{DynDigest, OutputSizeUser, core_api::BlockSizeUser};
     use digest::{ExtendableOutput, Update};
     use dyn_clone::{DynClone, clone_trait_object};
     use md5::Md5;
@@ -40,6 +40,14 @@ pub mod _hashlib {
         pub data: OptionalArg<ArgBytesLike>,
         #[pyarg(named, default = true)]
         usedforsecurity: bool,
+        #[pyarg(named, optional)]
+        digest_size: OptionalArg<usize>,
+        #[pyarg(named, optional)]
+        key: OptionalArg<ArgBytesLike>,
+        #[pyarg(named, optional)]
+        salt: OptionalArg<ArgBytesLike>,
+        #[pyarg(named, optional)]
+        person: OptionalArg<ArgBytesLike>,
     }
 
     impl From<NewHashArgs> for BlakeHashArgs {
@@ -47,6 +55,10 @@ pub mod _hashlib {
             Self {
                 data: args.data,
                 usedforsecurity: args.usedforsecurity,
+                digest_size: OptionalArg::Missing,
+                key: OptionalArg::Missing,
+                salt: OptionalArg::Missing,
+                person: OptionalArg::Missing,
             }
         }
     }
@@ -235,8 +247,8 @@ pub mod _hashlib {
             "sha3_512" => Ok(local_sha3_512(args.into()).into_pyobject(vm)),
             "shake_128" => Ok(local_shake_128(args.into()).into_pyobject(vm)),
             "shake_256" => Ok(local_shake_256(args.into()).into_pyobject(vm)),
-            "blake2b" => Ok(local_blake2b(args.into()).into_pyobject(vm)),
-            "blake2s" => Ok(local_blake2s(args.into()).into_pyobject(vm)),
+            "blake2b" => Ok(local_blake2b(args.into(), vm)?.into_pyobject(vm)),
+            "blake2s" => Ok(local_blake2s(args.into(), vm)?.into_pyobject(vm)),
             other => Err(vm.new_value_error(format!("Unknown hashing algorithm: {other}"))),
         }
     }
@@ -301,14 +313,54 @@ pub mod _hashlib {
         PyHasherXof::new("shake_256", HashXofWrapper::new_shake_256(args.string))
     }
 
+    /// `key`/`salt`/`person` change the initial parameter block that seeds
+    /// the compression function, so they can't be bolted on by hashing a
+    /// padded key as ordinary input -- that would silently produce digests
+    /// that don't match blake2b/blake2s keyed-MAC test vectors. Reject the
+    /// combination explicitly rather than ship a hash that merely looks
+    /// keyed/salted/personalized.
+    fn check_blake2_params_supported(args: &BlakeHashArgs, vm: &VirtualMachine) -> PyResult<()> {
+        let has_bytes = |arg: &OptionalArg<ArgBytesLike>| {
+            matches!(arg, OptionalArg::Present(b) if b.len() != 0)
+        };
+        if has_bytes(&args.key) || has_bytes(&args.salt) || has_bytes(&args.person) {
+            return Err(vm.new_not_implemented_error(
+                "RUSTPYTHON: blake2 key/salt/person are not yet supported".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
     #[pyfunction(name = "openssl_blake2b")]
-    pub fn local_blake2b(args: BlakeHashArgs) -> PyHasher {
-        PyHasher::new("blake2b", HashWrapper::new::<Blake2b512>(args.data))
+    pub fn local_blake2b(args: BlakeHashArgs, vm: &VirtualMachine) -> PyResult<PyHasher> {
+        check_blake2_params_supported(&args, vm)?;
+        if let OptionalArg::Present(digest_size) = args.digest_size
+            && digest_size != Blake2b512::output_size()
+        {
+            return Err(vm.new_not_implemented_error(
+                "RUSTPYTHON: blake2b digest_size other than 64 is not yet supported".to_owned(),
+            ));
+        }
+        Ok(PyHasher::new(
+            "blake2b",
+            HashWrapper::new::<Blake2b512>(args.data),
+        ))
     }
 
     #[pyfunction(name = "openssl_blake2s")]
-    pub fn local_blake2s(args: BlakeHashArgs) -> PyHasher {
-        PyHasher::new("blake2s", HashWrapper::new::<Blake2s256>(args.data))
+    pub fn local_blake2s(args: BlakeHashArgs, vm: &VirtualMachine) -> PyResult<PyHasher> {
+        check_blake2_params_supported(&args, vm)?;
+        if let OptionalArg::Present(digest_size) = args.digest_size
+            && digest_size != Blake2s256::output_size()
+        {
+            return Err(vm.new_not_implemented_error(
+                "RUSTPYTHON: blake2s digest_size other than 32 is not yet supported".to_owned(),
+            ));
+        }
+        Ok(PyHasher::new(
+            "blake2s",
+            HashWrapper::new::<Blake2s256>(args.data),
+        ))
     }
 
     #[pyfunction]
@@ -0,0 +1,195 @@
+pub(crate) use _interpreters::make_module;
+
+#[pymodule]
+mod _interpreters {
+    use crate::vm::{
+        AsObject, Interpreter, PyObjectRef, PyResult, Settings, VirtualMachine,
+        builtins::{PyBytes, PyFloat, PyInt, PyStr, PyStrRef},
+        function::FuncArgs,
+    };
+    use std::cell::RefCell;
+    use std::collections::{HashMap, VecDeque};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// A value that can cross the boundary between two interpreters.
+    ///
+    /// Interpreters created by [`create`] each get their own
+    /// `VirtualMachine` (modules, builtins, frames), but they all share the
+    /// one `Context` returned by `Context::genesis` (types, interned
+    /// strings, singletons); the `Context` is not isolated per interpreter.
+    /// Data passed between interpreters goes through a conversion like this
+    /// one, which copies the value, rather than moving the reference itself,
+    /// mirroring the "shareable objects only" restriction of PEP 554
+    /// channels.
+    #[derive(Clone)]
+    enum Shareable {
+        None,
+        Bool(bool),
+        Int(i64),
+        Float(f64),
+        Str(String),
+        Bytes(Vec<u8>),
+    }
+
+    fn to_shareable(obj: &PyObjectRef, vm: &VirtualMachine) -> PyResult<Shareable> {
+        if vm.is_none(obj) {
+            Ok(Shareable::None)
+        } else if obj.class().is(vm.ctx.types.bool_type) {
+            Ok(Shareable::Bool(obj.is(&vm.ctx.true_value)))
+        } else if let Some(int) = obj.downcast_ref::<PyInt>() {
+            Ok(Shareable::Int(int.try_to_primitive(vm)?))
+        } else if let Some(float) = obj.downcast_ref::<PyFloat>() {
+            Ok(Shareable::Float(float.to_f64()))
+        } else if let Some(s) = obj.downcast_ref::<PyStr>() {
+            Ok(Shareable::Str(s.as_str().to_owned()))
+        } else if let Some(b) = obj.downcast_ref::<PyBytes>() {
+            Ok(Shareable::Bytes(b.as_bytes().to_vec()))
+        } else {
+            Err(vm.new_type_error(format!(
+                "'{}' object is not shareable between interpreters",
+                obj.class().name()
+            )))
+        }
+    }
+
+    fn from_shareable(value: Shareable, vm: &VirtualMachine) -> PyObjectRef {
+        match value {
+            Shareable::None => vm.ctx.none(),
+            Shareable::Bool(b) => vm.ctx.new_bool(b).into(),
+            Shareable::Int(i) => vm.ctx.new_int(i).into(),
+            Shareable::Float(f) => vm.ctx.new_float(f).into(),
+            Shareable::Str(s) => vm.ctx.new_str(s).into(),
+            Shareable::Bytes(b) => vm.ctx.new_bytes(b).into(),
+        }
+    }
+
+    /// A created subinterpreter. Its [`Interpreter`] has its own
+    /// `VirtualMachine`, but shares the `Context` from `Context::genesis`
+    /// (types, interned strings, singletons) with every other interpreter
+    /// in the process. `run_string`
+    /// enters it with [`Interpreter::enter`], which pushes onto the same
+    /// thread-local `VM_STACK` that ordinary nested `enter` calls use, so
+    /// the one simplification here (versus full PEP 554/734
+    /// subinterpreters) is that a created interpreter can only be driven
+    /// from the OS thread that created it -- there is no independent
+    /// "interpreter has its own thread" story, just a separate VM.
+    struct SubInterpreter {
+        interp: Interpreter,
+    }
+
+    thread_local! {
+        static INTERPRETERS: RefCell<HashMap<u64, SubInterpreter>> = RefCell::new(HashMap::new());
+        static CHANNELS: RefCell<HashMap<u64, VecDeque<Shareable>>> = RefCell::new(HashMap::new());
+    }
+
+    static NEXT_INTERP_ID: AtomicU64 = AtomicU64::new(1);
+    static NEXT_CHANNEL_ID: AtomicU64 = AtomicU64::new(1);
+
+    #[pyfunction]
+    fn create(_args: FuncArgs) -> u64 {
+        let interp = Interpreter::with_init(Settings::default(), |vm| {
+            vm.add_native_modules(crate::get_module_inits());
+        });
+        let id = NEXT_INTERP_ID.fetch_add(1, Ordering::Relaxed);
+        INTERPRETERS.with(|reg| {
+            reg.borrow_mut().insert(id, SubInterpreter { interp });
+        });
+        id
+    }
+
+    #[pyfunction]
+    fn list_all(_args: FuncArgs) -> Vec<u64> {
+        INTERPRETERS.with(|reg| reg.borrow().keys().copied().collect())
+    }
+
+    #[pyfunction]
+    fn destroy(id: u64, vm: &VirtualMachine) -> PyResult<()> {
+        INTERPRETERS
+            .with(|reg| reg.borrow_mut().remove(&id))
+            .map(drop)
+            .ok_or_else(|| vm.new_value_error(format!("interpreter {id} not found")))
+    }
+
+    /// Run `source` to completion in the interpreter `id`, in a fresh
+    /// global namespace holding only `__builtins__` and `__file__` (not
+    /// that interpreter's `__main__` module). Blocks the calling thread,
+    /// the same way PEP 554's `exec`/`run_string` are specified to.
+    ///
+    /// An exception is not re-raised in the caller as the same exception;
+    /// a failure is reported as a `RuntimeError` in the caller carrying
+    /// the formatted traceback.
+    #[pyfunction]
+    fn run_string(id: u64, source: PyStrRef, vm: &VirtualMachine) -> PyResult<()> {
+        let failure = INTERPRETERS.with(|reg| {
+            let reg = reg.borrow();
+            let sub = reg
+                .get(&id)
+                .ok_or_else(|| vm.new_value_error(format!("interpreter {id} not found")))?;
+            sub.interp.enter(|sub_vm| {
+                let scope = sub_vm.new_scope_with_builtins();
+                match sub_vm.run_code_string(scope, source.as_str(), "<run_string>".to_owned()) {
+                    Ok(_) => Ok(None),
+                    Err(exc) => {
+                        let mut formatted = String::new();
+                        let _ = sub_vm.write_exception(&mut formatted, &exc);
+                        Ok(Some(formatted))
+                    }
+                }
+            })
+        })?;
+        match failure {
+            None => Ok(()),
+            Some(formatted) => Err(vm.new_runtime_error(format!(
+                "Traceback from interpreter {id} (most recent call last):\n{formatted}"
+            ))),
+        }
+    }
+
+    #[pyfunction]
+    fn is_shareable(obj: PyObjectRef, vm: &VirtualMachine) -> bool {
+        to_shareable(&obj, vm).is_ok()
+    }
+
+    #[pyfunction]
+    fn channel_create(_args: FuncArgs) -> u64 {
+        let id = NEXT_CHANNEL_ID.fetch_add(1, Ordering::Relaxed);
+        CHANNELS.with(|channels| {
+            channels.borrow_mut().insert(id, VecDeque::new());
+        });
+        id
+    }
+
+    #[pyfunction]
+    fn channel_send(id: u64, obj: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+        let value = to_shareable(&obj, vm)?;
+        CHANNELS.with(|channels| {
+            channels
+                .borrow_mut()
+                .get_mut(&id)
+                .map(|queue| queue.push_back(value))
+                .ok_or_else(|| vm.new_value_error(format!("channel {id} not found")))
+        })
+    }
+
+    #[pyfunction]
+    fn channel_recv(id: u64, vm: &VirtualMachine) -> PyResult<PyObjectRef> {
+        let value = CHANNELS.with(|channels| {
+            let mut channels = channels.borrow_mut();
+            let queue = channels
+                .get_mut(&id)
+                .ok_or_else(|| vm.new_value_error(format!("channel {id} not found")))?;
+            queue
+                .pop_front()
+                .ok_or_else(|| vm.new_value_error(format!("channel {id} is empty")))
+        })?;
+        Ok(from_shareable(value, vm))
+    }
+
+    #[pyfunction]
+    fn channel_close(id: u64, vm: &VirtualMachine) -> PyResult<()> {
+        CHANNELS
+            .with(|channels| channels.borrow_mut().remove(&id))
+            .map(drop)
+            .ok_or_else(|| vm.new_value_error(format!("channel {id} not found")))
+    }
+}
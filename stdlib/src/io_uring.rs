@@ -0,0 +1,45 @@
+//! NOT IMPLEMENTED: io_uring-backed socket/file I/O and asyncio event loop.
+//!
+//! The request this module was scaffolded for asked for an io_uring backend
+//! wired into the socket/file layer *and* the native asyncio event loop,
+//! with graceful fallback to epoll. Neither integration exists: this file
+//! is only a probe (can a ring be set up on this kernel/host at all) with
+//! nothing in the tree calling into it. That is a capability check, not the
+//! requested backend, and should not be mistaken for one -- the actual
+//! rewiring of the socket/file layer and event loop to pick a backend at
+//! runtime is still outstanding and is a substantially larger, riskier
+//! change than this probe. It is only compiled with the `io-uring`
+//! feature, and `#[allow(dead_code)]` keeps that build free of
+//! unused-code warnings. No
+//! stdlib module depends on this being available, so embedders who don't
+//! need the extra throughput pay nothing either way.
+
+#![allow(dead_code)] // unused until the socket/file layer picks a backend at runtime
+
+use std::io;
+
+/// An io_uring instance, set up eagerly by [`Backend::probe`]. No socket
+/// or file code uses it; it holds only the ring and has no op wiring.
+pub struct Backend {
+    ring: io_uring::IoUring,
+}
+
+impl Backend {
+    /// Try to set up an io_uring instance with a modest default queue depth.
+    ///
+    /// Returns `Err` on kernels without io_uring support (< 5.1) or when the
+    /// process is denied the `io_uring_setup` syscall (e.g. seccomp), in
+    /// which case the caller should keep using the epoll-based path.
+    pub fn probe() -> io::Result<Self> {
+        io_uring::IoUring::new(256).map(|ring| Self { ring })
+    }
+
+    /// Whether the current process can use the io_uring backend at all.
+    pub fn is_available() -> bool {
+        Self::probe().is_ok()
+    }
+
+    pub(crate) fn ring(&mut self) -> &mut io_uring::IoUring {
+        &mut self.ring
+    }
+}
@@ -5,15 +5,19 @@ mod machinery;
 mod _json {
     use super::machinery;
     use crate::vm::{
-        AsObject, Py, PyObjectRef, PyPayload, PyResult, VirtualMachine,
-        builtins::{PyBaseExceptionRef, PyStrRef, PyType, PyTypeRef},
+        AsObject, Py, PyObjectRef, PyPayload, PyResult, TryFromObject, VirtualMachine,
+        builtins::{
+            PyBaseExceptionRef, PyDict, PyDictRef, PyFloat, PyInt, PyList, PyStr, PyStrRef,
+            PyTuple, PyType, PyTypeRef,
+        },
         convert::{ToPyObject, ToPyResult},
         function::{IntoFuncArgs, OptionalArg},
         protocol::PyIterReturn,
-        types::{Callable, Constructor},
+        types::{Callable, Constructor, PyComparisonOp},
     };
     use malachite_bigint::BigInt;
     use rustpython_common::wtf8::Wtf8Buf;
+    use std::cmp::Ordering;
     use std::str::FromStr;
 
     #[pyattr(name = "make_scanner")]
@@ -27,7 +31,6 @@ mod _json {
         parse_float: Option<PyObjectRef>,
         parse_int: Option<PyObjectRef>,
         parse_constant: PyObjectRef,
-        ctx: PyObjectRef,
     }
 
     impl Constructor for JsonScanner {
@@ -59,88 +62,163 @@ mod _json {
                 parse_float,
                 parse_int,
                 parse_constant,
-                ctx,
             }
             .into_ref_with_type(vm, cls)
             .map(Into::into)
         }
     }
 
+    /// One level of JSON nesting currently being built, as an explicit stack
+    /// frame rather than a Rust call frame -- see [`JsonScanner::parse_container`].
+    enum ContainerFrame {
+        Array(Vec<PyObjectRef>),
+        Object {
+            pairs: Vec<(PyObjectRef, PyObjectRef)>,
+            /// `Some(key)` once a `"key":` has been scanned and a value is
+            /// still owed for it; `None` while looking for the next key (or
+            /// the closing brace).
+            pending_key: Option<PyObjectRef>,
+        },
+    }
+
+    /// `parse_container`'s `idx` is a *character* index (to match the
+    /// positions CPython's pure-Python `json.scanner` reports), but `full`
+    /// is UTF-8 bytes underneath, and `idx` only ever moves forward while a
+    /// container is parsed. Re-deriving a byte offset with
+    /// `s.chars().nth(idx)` from byte 0 on every lookup would make an
+    /// N-character array/object body O(N^2). `Cursor` instead remembers the
+    /// last character/byte position it resolved and walks forward from
+    /// there, so a full parse stays O(N) the way the loop it backs already
+    /// is.
+    struct Cursor<'a> {
+        full: &'a str,
+        char_idx: usize,
+        byte_idx: usize,
+    }
+
+    impl<'a> Cursor<'a> {
+        fn new(full: &'a str) -> Self {
+            Self {
+                full,
+                char_idx: 0,
+                byte_idx: 0,
+            }
+        }
+
+        /// Byte offset of character index `idx`, which must be `>=` the
+        /// index passed to the previous call.
+        fn byte_offset(&mut self, idx: usize) -> usize {
+            if idx < self.char_idx {
+                // The parser never seeks backwards; reset defensively rather
+                // than return a wrong offset if that assumption ever breaks.
+                self.char_idx = 0;
+                self.byte_idx = 0;
+            }
+            while self.char_idx < idx {
+                let Some(c) = self.full[self.byte_idx..].chars().next() else {
+                    self.char_idx = idx;
+                    break;
+                };
+                self.byte_idx += c.len_utf8();
+                self.char_idx += 1;
+            }
+            self.byte_idx
+        }
+
+        fn char_at(&mut self, idx: usize) -> Option<char> {
+            let byte_idx = self.byte_offset(idx);
+            self.full[byte_idx..].chars().next()
+        }
+
+        fn str_from(&mut self, idx: usize) -> &'a str {
+            let byte_idx = self.byte_offset(idx);
+            &self.full[byte_idx..]
+        }
+
+        fn skip_ws(&mut self, mut idx: usize) -> usize {
+            while matches!(self.char_at(idx), Some(' ' | '\t' | '\n' | '\r')) {
+                idx += 1;
+            }
+            idx
+        }
+    }
+
+    fn decode_err(msg: &str, pystr: PyStrRef, pos: usize, vm: &VirtualMachine) -> PyBaseExceptionRef {
+        py_decode_error(
+            machinery::DecodeError {
+                msg: msg.to_owned(),
+                pos,
+            },
+            pystr,
+            vm,
+        )
+    }
+
     #[pyclass(with(Callable, Constructor))]
     impl JsonScanner {
-        fn parse(
+        fn parse(&self, s: &str, pystr: PyStrRef, idx: usize, vm: &VirtualMachine) -> PyResult<PyIterReturn> {
+            if s.is_empty() {
+                return Ok(PyIterReturn::StopIteration(Some(
+                    vm.ctx.new_int(idx).into(),
+                )));
+            }
+            match s.chars().next().unwrap() {
+                '{' => {
+                    let (obj, end) = self.parse_container(pystr, idx + 1, true, vm)?;
+                    Ok(PyIterReturn::Return(vm.new_tuple((obj, end)).into()))
+                }
+                '[' => {
+                    let (arr, end) = self.parse_container(pystr, idx + 1, false, vm)?;
+                    Ok(PyIterReturn::Return(vm.new_tuple((arr, end)).into()))
+                }
+                _ => match self.parse_scalar(s, pystr, idx, vm)? {
+                    Some((value, end)) => Ok(PyIterReturn::Return(vm.new_tuple((value, end)).into())),
+                    None => Ok(PyIterReturn::StopIteration(Some(
+                        vm.ctx.new_int(idx).into(),
+                    ))),
+                },
+            }
+        }
+
+        /// Parses everything `parse` can return *other* than a `{`/`[`
+        /// container: a string, a number, or one of the `null`/`true`/`false`/
+        /// `NaN`/`Infinity`/`-Infinity` literals.
+        fn parse_scalar(
             &self,
             s: &str,
             pystr: PyStrRef,
             idx: usize,
-            scan_once: PyObjectRef,
             vm: &VirtualMachine,
-        ) -> PyResult<PyIterReturn> {
-            let c = match s.chars().next() {
-                Some(c) => c,
-                None => {
-                    return Ok(PyIterReturn::StopIteration(Some(
-                        vm.ctx.new_int(idx).into(),
-                    )));
-                }
-            };
+        ) -> PyResult<Option<(PyObjectRef, usize)>> {
+            let c = s.chars().next().unwrap();
             let next_idx = idx + c.len_utf8();
-            match c {
-                '"' => {
-                    return scanstring(pystr, next_idx, OptionalArg::Present(self.strict), vm)
-                        .map(|x| PyIterReturn::Return(x.to_pyobject(vm)));
-                }
-                '{' => {
-                    // TODO: parse the object in rust
-                    let parse_obj = self.ctx.get_attr("parse_object", vm)?;
-                    let result = parse_obj.call(
-                        (
-                            (pystr, next_idx),
-                            self.strict,
-                            scan_once,
-                            self.object_hook.clone(),
-                            self.object_pairs_hook.clone(),
-                        ),
-                        vm,
-                    );
-                    return PyIterReturn::from_pyresult(result, vm);
-                }
-                '[' => {
-                    // TODO: parse the array in rust
-                    let parse_array = self.ctx.get_attr("parse_array", vm)?;
-                    return PyIterReturn::from_pyresult(
-                        parse_array.call(((pystr, next_idx), scan_once), vm),
-                        vm,
-                    );
-                }
-                _ => {}
+            if c == '"' {
+                let (value, end) =
+                    scanstring(pystr, next_idx, OptionalArg::Present(self.strict), vm)?;
+                return Ok(Some((value.to_pyobject(vm), end)));
             }
 
-            macro_rules! parse_const {
-                ($s:literal, $val:expr) => {
-                    if s.starts_with($s) {
-                        return Ok(PyIterReturn::Return(
-                            vm.new_tuple(($val, idx + $s.len())).into(),
-                        ));
-                    }
-                };
+            if s.starts_with("null") {
+                return Ok(Some((vm.ctx.none(), idx + 4)));
+            }
+            if s.starts_with("true") {
+                return Ok(Some((vm.ctx.new_bool(true).into(), idx + 4)));
+            }
+            if s.starts_with("false") {
+                return Ok(Some((vm.ctx.new_bool(false).into(), idx + 5)));
             }
-
-            parse_const!("null", vm.ctx.none());
-            parse_const!("true", true);
-            parse_const!("false", false);
 
             if let Some((res, len)) = self.parse_number(s, vm) {
-                return Ok(PyIterReturn::Return(vm.new_tuple((res?, idx + len)).into()));
+                return Ok(Some((res?, idx + len)));
             }
 
             macro_rules! parse_constant {
                 ($s:literal) => {
                     if s.starts_with($s) {
-                        return Ok(PyIterReturn::Return(
-                            vm.new_tuple((self.parse_constant.call(($s,), vm)?, idx + $s.len()))
-                                .into(),
-                        ));
+                        return Ok(Some((
+                            self.parse_constant.call(($s,), vm)?,
+                            idx + $s.len(),
+                        )));
                     }
                 };
             }
@@ -149,9 +227,230 @@ mod _json {
             parse_constant!("Infinity");
             parse_constant!("-Infinity");
 
-            Ok(PyIterReturn::StopIteration(Some(
-                vm.ctx.new_int(idx).into(),
-            )))
+            Ok(None)
+        }
+
+        /// Parses the body of a `{...}` or `[...]` (the opening bracket has
+        /// already been consumed; `start_idx` points right after it) without
+        /// ever recursing into `parse`/`parse_container` for nested
+        /// containers -- each `{`/`[` just pushes another [`ContainerFrame`]
+        /// onto `stack` instead, so a document nested thousands of levels
+        /// deep is bounded by heap space, not the Rust call stack.
+        fn parse_container(
+            &self,
+            pystr: PyStrRef,
+            start_idx: usize,
+            is_object: bool,
+            vm: &VirtualMachine,
+        ) -> PyResult<(PyObjectRef, usize)> {
+            let full = pystr.as_str().to_owned();
+            let mut cursor = Cursor::new(full.as_str());
+            let mut idx = start_idx;
+            let mut stack = vec![if is_object {
+                ContainerFrame::Object {
+                    pairs: Vec::new(),
+                    pending_key: None,
+                }
+            } else {
+                ContainerFrame::Array(Vec::new())
+            }];
+            // A just-produced value (a scalar, or a container that just
+            // closed) waiting to be attached to whatever frame is now on
+            // top of the stack.
+            let mut ready_value: Option<PyObjectRef> = None;
+
+            loop {
+                if let Some(value) = ready_value.take() {
+                    match stack.last_mut().unwrap() {
+                        ContainerFrame::Array(values) => values.push(value),
+                        ContainerFrame::Object { pairs, pending_key } => {
+                            let key = pending_key
+                                .take()
+                                .expect("a value is only ever produced for a pending key");
+                            pairs.push((key, value));
+                        }
+                    }
+                }
+
+                idx = cursor.skip_ws(idx);
+
+                let awaiting_value_for_key = matches!(
+                    stack.last(),
+                    Some(ContainerFrame::Object {
+                        pending_key: Some(_),
+                        ..
+                    })
+                );
+                if awaiting_value_for_key {
+                    if cursor.char_at(idx) != Some(':') {
+                        return Err(decode_err(
+                            "Expecting ':' delimiter",
+                            pystr.clone(),
+                            idx,
+                            vm,
+                        ));
+                    }
+                    idx = cursor.skip_ws(idx + 1);
+                    idx = self.push_value(
+                        &mut cursor,
+                        &pystr,
+                        idx,
+                        &mut stack,
+                        &mut ready_value,
+                        vm,
+                    )?;
+                    continue;
+                }
+
+                match stack.last_mut().unwrap() {
+                    ContainerFrame::Array(values) => {
+                        if cursor.char_at(idx) == Some(']') {
+                            let list: PyObjectRef =
+                                vm.ctx.new_list(std::mem::take(values)).into();
+                            idx += 1;
+                            stack.pop();
+                            if stack.is_empty() {
+                                return Ok((list, idx));
+                            }
+                            ready_value = Some(list);
+                            continue;
+                        }
+                        if !values.is_empty() {
+                            let comma_idx = idx;
+                            if cursor.char_at(idx) != Some(',') {
+                                return Err(decode_err(
+                                    "Expecting ',' delimiter",
+                                    pystr.clone(),
+                                    idx,
+                                    vm,
+                                ));
+                            }
+                            idx = cursor.skip_ws(idx + 1);
+                            if cursor.char_at(idx) == Some(']') {
+                                return Err(decode_err(
+                                    "Illegal trailing comma before end of array",
+                                    pystr.clone(),
+                                    comma_idx,
+                                    vm,
+                                ));
+                            }
+                        }
+                    }
+                    ContainerFrame::Object { pairs, .. } => {
+                        if cursor.char_at(idx) == Some('}') {
+                            let obj = self.build_object(std::mem::take(pairs), vm)?;
+                            idx += 1;
+                            stack.pop();
+                            if stack.is_empty() {
+                                return Ok((obj, idx));
+                            }
+                            ready_value = Some(obj);
+                            continue;
+                        }
+                        if !pairs.is_empty() {
+                            let comma_idx = idx;
+                            if cursor.char_at(idx) != Some(',') {
+                                return Err(decode_err(
+                                    "Expecting ',' delimiter",
+                                    pystr.clone(),
+                                    idx,
+                                    vm,
+                                ));
+                            }
+                            idx = cursor.skip_ws(idx + 1);
+                            if cursor.char_at(idx) == Some('}') {
+                                return Err(decode_err(
+                                    "Illegal trailing comma before end of object",
+                                    pystr.clone(),
+                                    comma_idx,
+                                    vm,
+                                ));
+                            }
+                        }
+                        if cursor.char_at(idx) != Some('"') {
+                            return Err(decode_err(
+                                "Expecting property name enclosed in double quotes",
+                                pystr.clone(),
+                                idx,
+                                vm,
+                            ));
+                        }
+                        let (key, end) = scanstring(
+                            pystr.clone(),
+                            idx + 1,
+                            OptionalArg::Present(self.strict),
+                            vm,
+                        )?;
+                        idx = cursor.skip_ws(end);
+                        if let ContainerFrame::Object { pending_key, .. } = stack.last_mut().unwrap() {
+                            *pending_key = Some(key.to_pyobject(vm));
+                        }
+                        continue;
+                    }
+                }
+
+                idx =
+                    self.push_value(&mut cursor, &pystr, idx, &mut stack, &mut ready_value, vm)?;
+            }
+        }
+
+        /// Parses the value at `idx`: pushes a new frame for a nested
+        /// `{`/`[` (handled by the next loop iteration), or parses a scalar
+        /// directly into `ready_value`. Returns the index just past what it
+        /// consumed.
+        fn push_value(
+            &self,
+            cursor: &mut Cursor<'_>,
+            pystr: &PyStrRef,
+            idx: usize,
+            stack: &mut Vec<ContainerFrame>,
+            ready_value: &mut Option<PyObjectRef>,
+            vm: &VirtualMachine,
+        ) -> PyResult<usize> {
+            match cursor.char_at(idx) {
+                Some('{') => {
+                    stack.push(ContainerFrame::Object {
+                        pairs: Vec::new(),
+                        pending_key: None,
+                    });
+                    Ok(idx + 1)
+                }
+                Some('[') => {
+                    stack.push(ContainerFrame::Array(Vec::new()));
+                    Ok(idx + 1)
+                }
+                _ => match self.parse_scalar(cursor.str_from(idx), pystr.clone(), idx, vm)? {
+                    Some((value, end)) => {
+                        *ready_value = Some(value);
+                        Ok(end)
+                    }
+                    None => Err(decode_err("Expecting value", pystr.clone(), idx, vm)),
+                },
+            }
+        }
+
+        fn build_object(
+            &self,
+            pairs: Vec<(PyObjectRef, PyObjectRef)>,
+            vm: &VirtualMachine,
+        ) -> PyResult {
+            if let Some(hook) = &self.object_pairs_hook {
+                let pairs_list = vm.ctx.new_list(
+                    pairs
+                        .into_iter()
+                        .map(|(k, v)| vm.new_tuple((k, v)).into())
+                        .collect(),
+                );
+                return hook.call((pairs_list,), vm);
+            }
+            let dict = vm.ctx.new_dict();
+            for (k, v) in pairs {
+                dict.set_item(&*k, v, vm)?;
+            }
+            if let Some(hook) = &self.object_hook {
+                return hook.call((dict,), vm);
+            }
+            Ok(dict.into())
         }
 
         fn parse_number(&self, s: &str, vm: &VirtualMachine) -> Option<(PyResult, usize)> {
@@ -202,14 +501,8 @@ mod _json {
             if idx > 0 && chars.nth(idx - 1).is_none() {
                 PyIterReturn::StopIteration(Some(vm.ctx.new_int(idx).into())).to_pyresult(vm)
             } else {
-                zelf.parse(
-                    chars.as_str(),
-                    pystr.clone(),
-                    idx,
-                    zelf.to_owned().into(),
-                    vm,
-                )
-                .and_then(|x| x.to_pyresult(vm))
+                zelf.parse(chars.as_str(), pystr.clone(), idx, vm)
+                    .and_then(|x| x.to_pyresult(vm))
             }
         }
     }
@@ -258,4 +551,298 @@ mod _json {
         machinery::scanstring(s.as_wtf8(), end, strict.unwrap_or(true))
             .map_err(|e| py_decode_error(e, s, vm))
     }
+
+    #[derive(FromArgs)]
+    struct EncoderArgs {
+        #[pyarg(positional)]
+        markers: Option<PyDictRef>,
+        #[pyarg(positional)]
+        default: PyObjectRef,
+        #[pyarg(positional)]
+        encoder: PyObjectRef,
+        #[pyarg(positional)]
+        indent: Option<PyStrRef>,
+        #[pyarg(positional)]
+        key_separator: PyStrRef,
+        #[pyarg(positional)]
+        item_separator: PyStrRef,
+        #[pyarg(positional)]
+        sort_keys: bool,
+        #[pyarg(positional)]
+        skipkeys: bool,
+        #[pyarg(positional)]
+        allow_nan: bool,
+    }
+
+    /// The C-accelerated counterpart to `json.encoder._make_iterencode`; see
+    /// that function for the reference algorithm this mirrors step for step.
+    #[pyattr(name = "make_encoder")]
+    #[pyclass(name = "Encoder")]
+    #[derive(Debug, PyPayload)]
+    struct JsonEncoder {
+        markers: Option<PyDictRef>,
+        default: PyObjectRef,
+        encoder: PyObjectRef,
+        indent: Option<String>,
+        key_separator: PyStrRef,
+        item_separator: PyStrRef,
+        sort_keys: bool,
+        skipkeys: bool,
+        allow_nan: bool,
+    }
+
+    impl Constructor for JsonEncoder {
+        type Args = EncoderArgs;
+
+        fn py_new(cls: PyTypeRef, args: Self::Args, vm: &VirtualMachine) -> PyResult {
+            Self {
+                markers: args.markers,
+                default: args.default,
+                encoder: args.encoder,
+                indent: args.indent.map(|s| s.as_str().to_owned()),
+                key_separator: args.key_separator,
+                item_separator: args.item_separator,
+                sort_keys: args.sort_keys,
+                skipkeys: args.skipkeys,
+                allow_nan: args.allow_nan,
+            }
+            .into_ref_with_type(vm, cls)
+            .map(Into::into)
+        }
+    }
+
+    #[pyclass(with(Callable, Constructor))]
+    impl JsonEncoder {
+        fn indent_str(&self, level: usize) -> Option<String> {
+            self.indent.as_ref().map(|unit| unit.repeat(level))
+        }
+
+        fn enter_marker(&self, o: &PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+            if let Some(markers) = &self.markers {
+                let key = o.get_id();
+                if markers.contains_key(&key, vm) {
+                    return Err(vm.new_value_error("Circular reference detected"));
+                }
+                markers.set_item(&key, o.clone(), vm)?;
+            }
+            Ok(())
+        }
+
+        fn exit_marker(&self, o: &PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+            if let Some(markers) = &self.markers {
+                markers.del_item(&o.get_id(), vm)?;
+            }
+            Ok(())
+        }
+
+        fn float_str(&self, f: f64, vm: &VirtualMachine) -> PyResult<String> {
+            if f.is_nan() {
+                if !self.allow_nan {
+                    return Err(vm.new_value_error(
+                        "Out of range float values are not JSON compliant: nan".to_owned(),
+                    ));
+                }
+                return Ok("NaN".to_owned());
+            }
+            if f.is_infinite() {
+                if !self.allow_nan {
+                    return Err(vm.new_value_error(format!(
+                        "Out of range float values are not JSON compliant: {f}"
+                    )));
+                }
+                return Ok(if f > 0.0 { "Infinity" } else { "-Infinity" }.to_owned());
+            }
+            Ok(crate::vm::literal::float::to_string(f))
+        }
+
+        /// Converts a dict key that isn't already a string into the bare
+        /// (unquoted) text CPython would substitute for it, or `None` if it
+        /// should be dropped because `skipkeys` is set.
+        fn coerce_key(&self, key: &PyObjectRef, vm: &VirtualMachine) -> PyResult<Option<String>> {
+            if let Some(s) = key.downcast_ref::<PyStr>() {
+                return Ok(Some(s.as_str().to_owned()));
+            }
+            if key.is(&vm.ctx.true_value) {
+                return Ok(Some("true".to_owned()));
+            }
+            if key.is(&vm.ctx.false_value) {
+                return Ok(Some("false".to_owned()));
+            }
+            if vm.is_none(key) {
+                return Ok(Some("null".to_owned()));
+            }
+            if let Some(f) = key.downcast_ref::<PyFloat>() {
+                return Ok(Some(self.float_str(f.to_f64(), vm)?));
+            }
+            if let Some(i) = key.downcast_ref::<PyInt>() {
+                return Ok(Some(i.as_bigint().to_string()));
+            }
+            if self.skipkeys {
+                return Ok(None);
+            }
+            Err(vm.new_type_error(format!(
+                "keys must be str, int, float, bool or None, not {}",
+                key.class().name()
+            )))
+        }
+
+        fn write_quoted(&self, s: &str, buf: &mut String, vm: &VirtualMachine) -> PyResult<()> {
+            let quoted = self.encoder.call((s,), vm)?;
+            let quoted = PyStrRef::try_from_object(vm, quoted)?;
+            buf.push_str(quoted.as_str());
+            Ok(())
+        }
+
+        fn write_value(&self, o: &PyObjectRef, level: usize, buf: &mut String, vm: &VirtualMachine) -> PyResult<()> {
+            if let Some(s) = o.downcast_ref::<PyStr>() {
+                self.write_quoted(s.as_str(), buf, vm)?;
+            } else if vm.is_none(o) {
+                buf.push_str("null");
+            } else if o.is(&vm.ctx.true_value) {
+                buf.push_str("true");
+            } else if o.is(&vm.ctx.false_value) {
+                buf.push_str("false");
+            } else if let Some(i) = o.downcast_ref::<PyInt>() {
+                buf.push_str(&i.as_bigint().to_string());
+            } else if let Some(f) = o.downcast_ref::<PyFloat>() {
+                buf.push_str(&self.float_str(f.to_f64(), vm)?);
+            } else if let Some(list) = o.downcast_ref::<PyList>() {
+                let items = list.borrow_vec().to_vec();
+                self.write_sequence(&items, level, o, buf, vm)?;
+            } else if let Some(tuple) = o.downcast_ref::<PyTuple>() {
+                let items: &[PyObjectRef] = &**tuple;
+                self.write_sequence(items, level, o, buf, vm)?;
+            } else if let Some(dict) = o.downcast_ref::<PyDict>() {
+                self.write_dict(dict, level, o, buf, vm)?;
+            } else {
+                self.write_default(o, level, buf, vm)?;
+            }
+            Ok(())
+        }
+
+        fn write_sequence(
+            &self,
+            items: &[PyObjectRef],
+            level: usize,
+            marker_obj: &PyObjectRef,
+            buf: &mut String,
+            vm: &VirtualMachine,
+        ) -> PyResult<()> {
+            if items.is_empty() {
+                buf.push_str("[]");
+                return Ok(());
+            }
+            self.enter_marker(marker_obj, vm)?;
+            buf.push('[');
+            let next_level = level + 1;
+            let newline_indent = self.indent_str(next_level);
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    buf.push_str(self.item_separator.as_str());
+                }
+                if let Some(ind) = &newline_indent {
+                    buf.push('\n');
+                    buf.push_str(ind);
+                }
+                self.write_value(item, next_level, buf, vm)?;
+            }
+            if self.indent.is_some() {
+                buf.push('\n');
+                if let Some(ind) = self.indent_str(level) {
+                    buf.push_str(&ind);
+                }
+            }
+            buf.push(']');
+            self.exit_marker(marker_obj, vm)?;
+            Ok(())
+        }
+
+        fn write_dict(
+            &self,
+            dict: &Py<PyDict>,
+            level: usize,
+            marker_obj: &PyObjectRef,
+            buf: &mut String,
+            vm: &VirtualMachine,
+        ) -> PyResult<()> {
+            let mut items: Vec<(PyObjectRef, PyObjectRef)> = dict.into_iter().collect();
+            if items.is_empty() {
+                buf.push_str("{}");
+                return Ok(());
+            }
+            if self.sort_keys {
+                let mut sort_err = None;
+                items.sort_by(|a, b| {
+                    if sort_err.is_some() {
+                        return Ordering::Equal;
+                    }
+                    match a.0.rich_compare_bool(&b.0, PyComparisonOp::Lt, vm) {
+                        Ok(true) => Ordering::Less,
+                        Ok(false) => Ordering::Greater,
+                        Err(e) => {
+                            sort_err = Some(e);
+                            Ordering::Equal
+                        }
+                    }
+                });
+                if let Some(e) = sort_err {
+                    return Err(e);
+                }
+            }
+            self.enter_marker(marker_obj, vm)?;
+            buf.push('{');
+            let next_level = level + 1;
+            let newline_indent = self.indent_str(next_level);
+            let mut first = true;
+            for (key, value) in items {
+                let Some(key_text) = self.coerce_key(&key, vm)? else {
+                    continue;
+                };
+                if first {
+                    first = false;
+                    if let Some(ind) = &newline_indent {
+                        buf.push('\n');
+                        buf.push_str(ind);
+                    }
+                } else {
+                    buf.push_str(self.item_separator.as_str());
+                    if let Some(ind) = &newline_indent {
+                        buf.push('\n');
+                        buf.push_str(ind);
+                    }
+                }
+                self.write_quoted(&key_text, buf, vm)?;
+                buf.push_str(self.key_separator.as_str());
+                self.write_value(&value, next_level, buf, vm)?;
+            }
+            if !first && self.indent.is_some() {
+                buf.push('\n');
+                if let Some(ind) = self.indent_str(level) {
+                    buf.push_str(&ind);
+                }
+            }
+            buf.push('}');
+            self.exit_marker(marker_obj, vm)?;
+            Ok(())
+        }
+
+        fn write_default(&self, o: &PyObjectRef, level: usize, buf: &mut String, vm: &VirtualMachine) -> PyResult<()> {
+            self.enter_marker(o, vm)?;
+            let replacement = self.default.call((o.clone(),), vm)?;
+            self.write_value(&replacement, level, buf, vm)?;
+            self.exit_marker(o, vm)?;
+            Ok(())
+        }
+    }
+
+    impl Callable for JsonEncoder {
+        type Args = (PyObjectRef, isize);
+        fn call(zelf: &Py<Self>, (obj, level): Self::Args, vm: &VirtualMachine) -> PyResult {
+            let level = usize::try_from(level)
+                .map_err(|_| vm.new_value_error("indent level cannot be negative"))?;
+            let mut buf = String::new();
+            zelf.write_value(&obj, level, &mut buf, vm)?;
+            Ok(vm.ctx.new_list(vec![vm.ctx.new_str(buf).into()]).into())
+        }
+    }
 }
@@ -12,9 +12,11 @@ mod binascii;
 mod bisect;
 mod cmath;
 mod contextvars;
+mod continuation;
 mod csv;
 mod dis;
 mod gc;
+mod interpreters;
 
 mod bz2;
 mod compression; // internal module
@@ -31,13 +33,29 @@ mod sha3;
 mod sha512;
 
 mod json;
+mod lsprof;
 
 #[cfg(not(any(target_os = "ios", target_arch = "wasm32")))]
 mod locale;
 
+// RUSTPYTHON: NOT IMPLEMENTED. There is no native `_decimal` module here --
+// the request for one (contexts, rounding modes, FMA, quantize) is still
+// outstanding, not delivered by this comment. `Lib/decimal.py` does
+// `from _decimal import *` and only falls back to the pure-Python
+// `_pydecimal` on `ImportError` -- it has no way to detect a *partial*
+// native module, so shipping one that's missing context-based rounding,
+// quantize, FMA, or the special-value (NaN/Infinity/subnormal) handling
+// would silently replace a correct, if slow, implementation with a broken
+// one rather than degrade gracefully. A native module is only worth adding
+// once it's a complete drop-in for `_pydecimal`'s public surface; until
+// then `decimal` keeps using the pure-Python fallback, and the native
+// module remains a tracked follow-up rather than something to build partway.
 mod math;
 #[cfg(unix)]
 mod mmap;
+mod pickle;
+#[cfg(all(feature = "net", not(target_arch = "wasm32")))]
+mod net;
 mod opcode;
 mod pyexpat;
 mod pystruct;
@@ -58,6 +76,8 @@ mod fcntl;
 #[cfg(not(target_arch = "wasm32"))]
 mod multiprocessing;
 #[cfg(unix)]
+mod posixshmem;
+#[cfg(unix)]
 mod posixsubprocess;
 // libc is missing constants on redox
 #[cfg(all(unix, not(any(target_os = "android", target_os = "redox"))))]
@@ -91,6 +111,12 @@ mod uuid;
 #[cfg(feature = "tkinter")]
 mod tkinter;
 
+#[cfg(all(unix, not(target_os = "redox"), feature = "curses"))]
+mod curses;
+
+#[cfg(all(feature = "io-uring", target_os = "linux"))]
+mod io_uring;
+
 use rustpython_common as common;
 use rustpython_vm as vm;
 
@@ -122,10 +148,12 @@ pub fn get_module_inits() -> impl Iterator<Item = (Cow<'static, str>, StdlibInit
             "_bz2" => bz2::make_module,
             "cmath" => cmath::make_module,
             "_contextvars" => contextvars::make_module,
+            "_continuation" => continuation::make_module,
             "_csv" => csv::make_module,
             "_dis" => dis::make_module,
             "faulthandler" => faulthandler::make_module,
             "gc" => gc::make_module,
+            "_interpreters" => interpreters::make_module,
             "_hashlib" => hashlib::make_module,
             "_sha1" => sha1::make_module,
             "_sha3" => sha3::make_module,
@@ -134,7 +162,9 @@ pub fn get_module_inits() -> impl Iterator<Item = (Cow<'static, str>, StdlibInit
             "_md5" => md5::make_module,
             "_blake2" => blake2::make_module,
             "_json" => json::make_module,
+            "_lsprof" => lsprof::make_module,
             "math" => math::make_module,
+            "_pickle" => pickle::make_module,
             "pyexpat" => pyexpat::make_module,
             "_opcode" => opcode::make_module,
             "_random" => random::make_module,
@@ -159,6 +189,10 @@ pub fn get_module_inits() -> impl Iterator<Item = (Cow<'static, str>, StdlibInit
             "_multiprocessing" => multiprocessing::make_module,
             "_socket" => socket::make_module,
         }
+        #[cfg(all(feature = "net", not(target_arch = "wasm32")))]
+        {
+            "_net" => net::make_module,
+        }
         #[cfg(not(any(target_os = "android", target_arch = "wasm32")))]
         {
             "_lzma" => lzma::make_module,
@@ -178,6 +212,7 @@ pub fn get_module_inits() -> impl Iterator<Item = (Cow<'static, str>, StdlibInit
         // Unix-only
         #[cfg(unix)]
         {
+            "_posixshmem" => posixshmem::make_module,
             "_posixsubprocess" => posixsubprocess::make_module,
             "mmap" => mmap::make_module,
         }
@@ -210,5 +245,9 @@ pub fn get_module_inits() -> impl Iterator<Item = (Cow<'static, str>, StdlibInit
         {
             "_tkinter" => tkinter::make_module,
         }
+        #[cfg(all(unix, not(target_os = "redox"), feature = "curses"))]
+        {
+            "_curses" => curses::make_module,
+        }
     }
 }
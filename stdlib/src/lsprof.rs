@@ -0,0 +1,141 @@
+pub(crate) use _lsprof::make_module;
+
+#[pymodule]
+mod _lsprof {
+    use crate::vm::{
+        Py, PyObjectRef, PyPayload, PyResult, VirtualMachine,
+        builtins::PyStrRef,
+        function::OptionalArg,
+        types::{Callable, Constructor},
+    };
+    use indexmap::IndexMap;
+    use std::cell::RefCell;
+    use std::time::Instant;
+
+    /// Accumulated timing for a single (filename, lineno, name) call site,
+    /// mirroring the fields `pstats.Stats` expects from `_lsprof.Profiler.getstats()`.
+    #[derive(Debug, Default, Clone)]
+    struct Entry {
+        call_count: u64,
+        total_time: f64,
+        inline_time: f64,
+    }
+
+    #[derive(Debug, Default)]
+    struct ProfilerState {
+        entries: IndexMap<(String, u32, String), Entry>,
+        // stack of (key, call started at, time spent in callees so far)
+        stack: Vec<((String, u32, String), Instant, f64)>,
+    }
+
+    #[pyattr]
+    #[pyclass(name = "Profiler")]
+    #[derive(Debug, PyPayload)]
+    pub struct PyProfiler {
+        state: RefCell<ProfilerState>,
+        enabled: RefCell<bool>,
+    }
+
+    impl Constructor for PyProfiler {
+        type Args = OptionalArg<PyObjectRef>;
+        fn py_new(cls: crate::vm::builtins::PyTypeRef, _timer: Self::Args, vm: &VirtualMachine) -> PyResult {
+            Ok(Self {
+                state: RefCell::default(),
+                enabled: RefCell::new(false),
+            }
+            .into_ref_with_type(vm, cls)?
+            .into())
+        }
+    }
+
+    #[pyclass(with(Constructor, Callable))]
+    impl PyProfiler {
+        #[pymethod]
+        fn enable(zelf: crate::vm::PyRef<Self>, vm: &VirtualMachine) {
+            *zelf.enabled.borrow_mut() = true;
+            *vm.profile_func.borrow_mut() = zelf.into();
+        }
+
+        #[pymethod]
+        fn disable(&self, vm: &VirtualMachine) {
+            *self.enabled.borrow_mut() = false;
+            *vm.profile_func.borrow_mut() = vm.ctx.none();
+        }
+
+        #[pymethod]
+        fn clear(&self) {
+            *self.state.borrow_mut() = ProfilerState::default();
+        }
+
+        /// Return accumulated stats as `(filename, lineno, name, call_count, total_time,
+        /// inline_time)` tuples, in the shape `pstats.Stats` can consume.
+        #[pymethod]
+        fn getstats(&self, vm: &VirtualMachine) -> Vec<PyObjectRef> {
+            let state = self.state.borrow();
+            state
+                .entries
+                .iter()
+                .map(|((filename, lineno, name), entry)| {
+                    vm.ctx
+                        .new_tuple(vec![
+                            vm.ctx.new_str(filename.as_str()).into(),
+                            vm.ctx.new_int(*lineno).into(),
+                            vm.ctx.new_str(name.as_str()).into(),
+                            vm.ctx.new_int(entry.call_count).into(),
+                            vm.ctx.new_float(entry.total_time).into(),
+                            vm.ctx.new_float(entry.inline_time).into(),
+                        ])
+                        .into()
+                })
+                .collect()
+        }
+    }
+
+    impl Callable for PyProfiler {
+        // Matches the `sys.setprofile` callback shape: `(frame, event, arg)`.
+        type Args = (PyObjectRef, PyStrRef, OptionalArg<PyObjectRef>);
+
+        fn call(
+            zelf: &Py<Self>,
+            (frame, event, _arg): Self::Args,
+            _vm: &VirtualMachine,
+        ) -> PyResult<PyObjectRef> {
+            if !*zelf.enabled.borrow() {
+                return Ok(frame);
+            }
+            let Ok(frame) = frame.clone().downcast::<crate::vm::frame::Frame>() else {
+                return Ok(frame);
+            };
+            let code = &frame.code;
+            let key = (
+                code.co_filename().as_str().to_owned(),
+                code.code.first_line_number.map_or(0, |l| l.get()),
+                code.code.obj_name.as_str().to_owned(),
+            );
+
+            let mut state = zelf.state.borrow_mut();
+            match event.as_str() {
+                "call" | "c_call" => {
+                    state.stack.push((key, Instant::now(), 0.0));
+                }
+                "return" | "c_return" => {
+                    if let Some((key, started, child_time)) = state.stack.pop() {
+                        let elapsed = started.elapsed().as_secs_f64();
+                        if let Some((_, parent_started, parent_child_time)) =
+                            state.stack.last_mut()
+                        {
+                            let _ = parent_started;
+                            *parent_child_time += elapsed;
+                        }
+                        let entry = state.entries.entry(key).or_default();
+                        entry.call_count += 1;
+                        entry.total_time += elapsed;
+                        entry.inline_time += elapsed - child_time;
+                    }
+                }
+                _ => {}
+            }
+            Ok(frame.into())
+        }
+    }
+}
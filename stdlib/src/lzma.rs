@@ -33,7 +33,7 @@ mod _lzma {
         LZMA_PRESET_LEVEL_MASK as PRESET_LEVEL_MASK,
     };
     use rustpython_common::lock::PyMutex;
-    use rustpython_vm::builtins::{PyBaseExceptionRef, PyBytesRef, PyTypeRef};
+    use rustpython_vm::builtins::{PyBaseExceptionRef, PyBytesRef, PyDictRef, PyTypeRef};
     use rustpython_vm::convert::ToPyException;
     use rustpython_vm::function::ArgBytesLike;
     use rustpython_vm::types::Constructor;
@@ -142,7 +142,7 @@ mod _lzma {
         #[pyarg(any, optional)]
         mem_limit: Option<u64>,
         #[pyarg(any, optional)]
-        filters: Option<u32>,
+        filters: Option<Vec<PyObjectRef>>,
     }
 
     impl Constructor for LZMADecompressor {
@@ -152,13 +152,21 @@ mod _lzma {
             if args.format == FORMAT_RAW && args.mem_limit.is_some() {
                 return Err(vm.new_value_error("Cannot specify memory limit with FORMAT_RAW"));
             }
+            if args.format != FORMAT_RAW && args.filters.is_some() {
+                return Err(vm.new_value_error("Cannot specify filters except with FORMAT_RAW"));
+            }
             let mem_limit = args.mem_limit.unwrap_or(u64::MAX);
-            let filters = args.filters.unwrap_or(0);
             let stream_result = match args.format {
-                FORMAT_AUTO => Stream::new_auto_decoder(mem_limit, filters),
-                FORMAT_XZ => Stream::new_stream_decoder(mem_limit, filters),
+                FORMAT_AUTO => Stream::new_auto_decoder(mem_limit, 0),
+                FORMAT_XZ => Stream::new_stream_decoder(mem_limit, 0),
                 FORMAT_ALONE => Stream::new_lzma_decoder(mem_limit),
-                // TODO: FORMAT_RAW
+                FORMAT_RAW => {
+                    let filters = args
+                        .filters
+                        .ok_or_else(|| vm.new_value_error("Must specify filters for FORMAT_RAW"))?;
+                    let filters = parse_filter_chain_spec(filters, vm)?;
+                    Stream::new_raw_decoder(&filters)
+                }
                 _ => return Err(new_lzma_error("Invalid format", vm)),
             };
             Self {
@@ -301,8 +309,40 @@ mod _lzma {
                 vm,
             ));
         }
-        let filters = Filters::new();
-        for _item in filter_specs {}
+        let mut filters = Filters::new();
+        for item in filter_specs {
+            let spec = PyDictRef::try_from_object(vm, item)
+                .map_err(|_| vm.new_type_error("Filter specifier must be a dict"))?;
+            let id = spec
+                .get_item("id", vm)
+                .map_err(|_| new_lzma_error("Filter specifier must have an \"id\" entry", vm))?
+                .try_to_value::<u64>(vm)?;
+            macro_rules! preset_filter {
+                ($add:ident) => {{
+                    let preset = spec
+                        .get_item_opt("preset", vm)?
+                        .map(|p| p.try_to_value::<u32>(vm))
+                        .transpose()?
+                        .unwrap_or(PRESET_DEFAULT);
+                    let options = LzmaOptions::new_preset(preset)
+                        .map_err(|_| new_lzma_error("Invalid filter options", vm))?;
+                    filters.$add(&options);
+                }};
+            }
+            match id {
+                _ if id == FILTER_LZMA1 as u64 => preset_filter!(lzma1),
+                _ if id == FILTER_LZMA2 as u64 => preset_filter!(lzma2),
+                _ if id == FILTER_DELTA as u64 => {
+                    let dist = spec
+                        .get_item_opt("dist", vm)?
+                        .map(|d| d.try_to_value::<u32>(vm))
+                        .transpose()?
+                        .unwrap_or(1);
+                    filters.delta(dist);
+                }
+                _ => return Err(new_lzma_error("Unsupported filter id in filter chain", vm)),
+            }
+        }
         Ok(filters)
     }
 
@@ -343,6 +383,14 @@ mod _lzma {
                 Ok(stream)
             }
         }
+
+        fn init_raw(filter_specs: Option<Vec<PyObjectRef>>, vm: &VirtualMachine) -> PyResult<Stream> {
+            let filter_specs = filter_specs
+                .ok_or_else(|| vm.new_value_error("Must specify filters for FORMAT_RAW"))?;
+            let filters = parse_filter_chain_spec(filter_specs, vm)?;
+            Stream::new_raw_encoder(&filters)
+                .map_err(|_| new_lzma_error("Failed to initialize encoder", vm))
+        }
     }
 
     #[derive(FromArgs)]
@@ -387,7 +435,7 @@ mod _lzma {
             let stream = match args.format {
                 FORMAT_XZ => Self::init_xz(args.check, preset, args.filters, vm)?,
                 FORMAT_ALONE => Self::init_alone(preset, args.filters, vm)?,
-                // TODO: RAW
+                FORMAT_RAW => Self::init_raw(args.filters, vm)?,
                 _ => return Err(new_lzma_error("Invalid format", vm)),
             };
             Ok(Self {
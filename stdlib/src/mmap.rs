@@ -13,12 +13,13 @@ mod mmap {
         TryFromBorrowedObject, VirtualMachine, atomic_func,
         builtins::{PyBytes, PyBytesRef, PyInt, PyIntRef, PyTypeRef},
         byte::{bytes_from_object, value_from_object},
-        convert::ToPyException,
+        convert::{IntoPyException, ToPyException},
         function::{ArgBytesLike, FuncArgs, OptionalArg},
         protocol::{
             BufferDescriptor, BufferMethods, PyBuffer, PyMappingMethods, PySequenceMethods,
         },
         sliceable::{SaturatedSlice, SequenceIndex, SequenceIndexOp},
+        stdlib::os,
         types::{AsBuffer, AsMapping, AsSequence, Constructor, Representable},
     };
     use crossbeam_utils::atomic::AtomicCell;
@@ -533,8 +534,6 @@ mod mmap {
             Ok(m)
         }
 
-        /// TODO: impl resize
-        #[allow(dead_code)]
         fn check_resizeable(&self, vm: &VirtualMachine) -> PyResult<()> {
             if self.exports.load() > 0 {
                 return Err(vm.new_buffer_error("mmap can't resize with extant buffers exported."));
@@ -792,11 +791,58 @@ mod mmap {
             Ok(result)
         }
 
-        // TODO: supports resize
         #[pymethod]
-        fn resize(&self, _newsize: PyIntRef, vm: &VirtualMachine) -> PyResult<()> {
+        fn resize(&self, newsize: PyIntRef, vm: &VirtualMachine) -> PyResult<()> {
             self.check_resizeable(vm)?;
-            Err(vm.new_system_error("mmap: resizing not available--no mremap()"))
+
+            let new_size = newsize
+                .try_to_primitive::<usize>(vm)
+                .map_err(|_| vm.new_value_error("new size out of range"))?;
+
+            // `check_resizeable` only lets Write/Default maps through, both of which are
+            // backed by a `MmapObj::Write`.
+            let old_size = self.__len__();
+            let mut kept = {
+                let mmap = self.mmap.lock();
+                match mmap.as_ref().expect("mmap closed or invalid") {
+                    MmapObj::Write(mmap) => mmap[..old_size.min(new_size)].to_vec(),
+                    MmapObj::Read(_) => unreachable!("check_resizeable rejects read-only maps"),
+                }
+            };
+            kept.resize(new_size, 0);
+
+            // memmap2 has no `mremap`, so the resized mapping is rebuilt from scratch at the
+            // new size and the overlapping bytes are copied across.
+            let fd = self.fd.load();
+            let mut new_mmap = if fd == -1 {
+                MmapOptions::new()
+                    .len(new_size)
+                    .map_anon()
+                    .map_err(|e| e.to_pyexception(vm))?
+            } else {
+                let raw_fd = unsafe { crt_fd::Borrowed::try_borrow_raw(fd) }
+                    .map_err(|e| e.to_pyexception(vm))?;
+                let new_file_len = self.offset + new_size as libc::off_t;
+                os::ftruncate(raw_fd, new_file_len).map_err(|e| e.to_pyexception(vm))?;
+
+                let new_fd: crt_fd::Owned = unistd::dup(raw_fd)
+                    .map_err(|e| e.into_pyexception(vm))?
+                    .into();
+                let mut mmap_opt = MmapOptions::new();
+                let mmap_opt = mmap_opt.offset(self.offset.try_into().unwrap()).len(new_size);
+                let mmap = unsafe { mmap_opt.map_mut(&new_fd) }.map_err(|e| e.to_pyexception(vm))?;
+                self.fd.store(new_fd.into_raw());
+                mmap
+            };
+            new_mmap[..kept.len()].copy_from_slice(&kept);
+
+            *self.mmap.lock() = Some(MmapObj::Write(new_mmap));
+            self.size.store(new_size);
+            if self.pos() > new_size {
+                self.pos.store(new_size);
+            }
+
+            Ok(())
         }
 
         #[pymethod]
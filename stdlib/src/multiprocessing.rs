@@ -41,6 +41,304 @@ mod _multiprocessing {
     }
 }
 
-#[cfg(not(windows))]
+// `multiprocessing.synchronize` imports `SemLock` and `sem_unlink` unconditionally from this
+// module (falling back to raising ImportError itself if they're missing), and
+// `multiprocessing.shared_memory`/`resource_tracker` only need the generic `os`/`mmap` modules,
+// so POSIX named semaphores are the one primitive this module has to provide natively.
+#[cfg(unix)]
+#[pymodule]
+mod _multiprocessing {
+    use crate::common::lock::PyMutex;
+    use crate::vm::{
+        Context, Py, PyPayload, PyRef, PyResult, VirtualMachine,
+        builtins::{PyStrRef, PyType, PyTypeRef},
+        function::FuncArgs,
+        stdlib::os,
+        types::Constructor,
+    };
+    use crossbeam_utils::atomic::AtomicCell;
+    use std::ffi::CString;
+    use std::time::{Duration, Instant};
+
+    // multiprocessing.synchronize.RECURSIVE_MUTEX; the only other `kind`, SEMAPHORE, needs no
+    // special casing here since a plain semaphore's acquire/release just wraps sem_wait/sem_post.
+    const RECURSIVE_MUTEX: i32 = 0;
+
+    #[pyattr]
+    #[pyclass(module = "_multiprocessing", name = "SemLock")]
+    #[derive(PyPayload)]
+    struct PySemLock {
+        handle: AtomicCell<usize>,
+        kind: i32,
+        maxvalue: u32,
+        name: PyMutex<Option<String>>,
+        // only meaningful for RECURSIVE_MUTEX: how many times the owning thread has
+        // re-acquired the lock, and which thread that is (0 means "unowned").
+        count: AtomicCell<u32>,
+        owner: AtomicCell<usize>,
+    }
+
+    impl std::fmt::Debug for PySemLock {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("PySemLock").finish()
+        }
+    }
+
+    fn current_thread() -> usize {
+        unsafe { libc::pthread_self() as usize }
+    }
+
+    impl PySemLock {
+        fn sem(&self) -> *mut libc::sem_t {
+            self.handle.load() as *mut libc::sem_t
+        }
+    }
+
+    #[derive(FromArgs)]
+    struct SemLockNewArgs {
+        #[pyarg(positional)]
+        kind: i32,
+        #[pyarg(positional)]
+        value: u32,
+        #[pyarg(positional)]
+        maxvalue: u32,
+        #[pyarg(positional)]
+        name: PyStrRef,
+        #[pyarg(positional)]
+        unlink: bool,
+    }
+
+    impl Constructor for PySemLock {
+        type Args = SemLockNewArgs;
+
+        fn py_new(cls: PyTypeRef, args: Self::Args, vm: &VirtualMachine) -> PyResult {
+            let cname = CString::new(args.name.as_str())
+                .map_err(|_| vm.new_value_error("embedded null byte in semaphore name"))?;
+            let sem = unsafe {
+                libc::sem_open(
+                    cname.as_ptr(),
+                    libc::O_CREAT | libc::O_EXCL,
+                    0o600u32,
+                    args.value,
+                )
+            };
+            if sem == libc::SEM_FAILED {
+                return Err(os::errno_err(vm));
+            }
+            if args.unlink {
+                unsafe { libc::sem_unlink(cname.as_ptr()) };
+            }
+            let name = if args.unlink {
+                None
+            } else {
+                Some(args.name.as_str().to_owned())
+            };
+            Self {
+                handle: AtomicCell::new(sem as usize),
+                kind: args.kind,
+                maxvalue: args.maxvalue,
+                name: PyMutex::new(name),
+                count: AtomicCell::new(0),
+                owner: AtomicCell::new(0),
+            }
+            .into_ref_with_type(vm, cls)
+            .map(Into::into)
+        }
+    }
+
+    #[derive(FromArgs)]
+    struct AcquireArgs {
+        #[pyarg(any, default = true)]
+        block: bool,
+        #[pyarg(any, default)]
+        timeout: Option<f64>,
+    }
+
+    #[pyclass(with(Constructor))]
+    impl PySemLock {
+        #[extend_class]
+        fn extend_class_with_fields(ctx: &Context, class: &'static Py<PyType>) {
+            class.set_attr(
+                ctx.intern_str("SEM_VALUE_MAX"),
+                ctx.new_int(libc::SEM_VALUE_MAX).into(),
+            );
+        }
+
+        #[pygetset]
+        fn handle(&self) -> isize {
+            self.handle.load() as isize
+        }
+
+        #[pygetset]
+        fn kind(&self) -> i32 {
+            self.kind
+        }
+
+        #[pygetset]
+        fn maxvalue(&self) -> u32 {
+            self.maxvalue
+        }
+
+        #[pygetset]
+        fn name(&self) -> Option<String> {
+            self.name.lock().clone()
+        }
+
+        #[pymethod]
+        fn acquire(&self, args: AcquireArgs, vm: &VirtualMachine) -> PyResult<bool> {
+            let tid = current_thread();
+            if self.kind == RECURSIVE_MUTEX && self.count.load() > 0 && self.owner.load() == tid {
+                self.count.fetch_add(1);
+                return Ok(true);
+            }
+
+            let sem = self.sem();
+            // `sem_timedwait` isn't available on every unix we support (notably macOS), so
+            // block indefinitely via `sem_wait` when there's no deadline and otherwise poll
+            // with `sem_trywait`, backing off similarly to the generic pthread-free lock
+            // fallback used elsewhere for platforms without a native timed wait.
+            let deadline = if args.block {
+                args.timeout.map(|secs| Instant::now() + Duration::from_secs_f64(secs.max(0.0)))
+            } else {
+                Some(Instant::now())
+            };
+
+            let acquired = match deadline {
+                None => loop {
+                    if unsafe { libc::sem_wait(sem) } == 0 {
+                        break true;
+                    }
+                    match nix::errno::Errno::last() {
+                        nix::errno::Errno::EINTR => vm.check_signals()?,
+                        _ => return Err(os::errno_err(vm)),
+                    }
+                },
+                Some(deadline) => {
+                    let mut poll_interval = Duration::from_micros(500);
+                    loop {
+                        if unsafe { libc::sem_trywait(sem) } == 0 {
+                            break true;
+                        }
+                        match nix::errno::Errno::last() {
+                            nix::errno::Errno::EINTR => {
+                                vm.check_signals()?;
+                                continue;
+                            }
+                            nix::errno::Errno::EAGAIN => {}
+                            _ => return Err(os::errno_err(vm)),
+                        }
+                        if Instant::now() >= deadline {
+                            break false;
+                        }
+                        std::thread::sleep(poll_interval.min(deadline - Instant::now()));
+                        poll_interval = (poll_interval * 2).min(Duration::from_millis(20));
+                    }
+                }
+            };
+
+            if acquired && self.kind == RECURSIVE_MUTEX {
+                self.count.store(1);
+                self.owner.store(tid);
+            }
+            Ok(acquired)
+        }
+
+        #[pymethod]
+        fn release(&self, vm: &VirtualMachine) -> PyResult<()> {
+            if self.kind == RECURSIVE_MUTEX {
+                let count = self.count.load();
+                if count == 0 || self.owner.load() != current_thread() {
+                    return Err(vm.new_value_error("semaphore or lock released too many times"));
+                }
+                if count > 1 {
+                    self.count.store(count - 1);
+                    return Ok(());
+                }
+                self.count.store(0);
+                self.owner.store(0);
+            } else {
+                let mut value: libc::c_int = 0;
+                if unsafe { libc::sem_getvalue(self.sem(), &mut value) } == 0
+                    && value as u32 >= self.maxvalue
+                {
+                    return Err(vm.new_value_error("semaphore or lock released too many times"));
+                }
+            }
+            if unsafe { libc::sem_post(self.sem()) } != 0 {
+                return Err(os::errno_err(vm));
+            }
+            Ok(())
+        }
+
+        #[pymethod]
+        fn _after_fork(&self) {
+            // the child doesn't inherit whatever thread happened to be holding the
+            // lock in the parent, so forget about it, matching CPython's posix semlock.
+            self.count.store(0);
+            self.owner.store(0);
+        }
+
+        #[pyclassmethod]
+        fn _rebuild(
+            cls: PyTypeRef,
+            handle: isize,
+            kind: i32,
+            maxvalue: u32,
+            name: Option<PyStrRef>,
+            vm: &VirtualMachine,
+        ) -> PyResult<PyRef<Self>> {
+            let sem = match &name {
+                Some(name) => {
+                    let cname = CString::new(name.as_str())
+                        .map_err(|_| vm.new_value_error("embedded null byte in semaphore name"))?;
+                    let sem = unsafe { libc::sem_open(cname.as_ptr(), 0) };
+                    if sem == libc::SEM_FAILED {
+                        return Err(os::errno_err(vm));
+                    }
+                    sem
+                }
+                None => handle as *mut libc::sem_t,
+            };
+            Self {
+                handle: AtomicCell::new(sem as usize),
+                kind,
+                maxvalue,
+                name: PyMutex::new(name.map(|s| s.as_str().to_owned())),
+                count: AtomicCell::new(0),
+                owner: AtomicCell::new(0),
+            }
+            .into_ref_with_type(vm, cls)
+        }
+
+        #[pymethod]
+        fn __enter__(&self, vm: &VirtualMachine) -> PyResult<bool> {
+            self.acquire(
+                AcquireArgs {
+                    block: true,
+                    timeout: None,
+                },
+                vm,
+            )
+        }
+
+        #[pymethod]
+        fn __exit__(&self, _args: FuncArgs, vm: &VirtualMachine) -> PyResult<()> {
+            self.release(vm)
+        }
+    }
+
+    #[pyfunction]
+    fn sem_unlink(name: PyStrRef, vm: &VirtualMachine) -> PyResult<()> {
+        let cname = CString::new(name.as_str())
+            .map_err(|_| vm.new_value_error("embedded null byte in semaphore name"))?;
+        if unsafe { libc::sem_unlink(cname.as_ptr()) } != 0 {
+            Err(os::errno_err(vm))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
 #[pymodule]
 mod _multiprocessing {}
@@ -0,0 +1,97 @@
+pub(crate) use _pickle::make_module;
+
+// RUSTPYTHON: `Lib/pickle.py` guards `Pickler`/`Unpickler`/`dump`/`dumps`/
+// `load`/`loads` behind their own `try: from _pickle import (...) except
+// ImportError:` block, separate from the one that imports `PickleBuffer` --
+// so unlike `_decimal` (see the `_decimal` note in this crate's `lib.rs`),
+// shipping a native module that covers only `PickleBuffer` is a genuine,
+// honest drop-in for the narrower surface it claims: the accelerator import
+// for the opcode-level Pickler/Unpickler still raises `ImportError` and
+// `pickle.py` falls back to its pure-Python `_Pickler`/`_Unpickler`, while
+// protocol 5's out-of-band buffers (the part of the accelerator that's
+// cheap to get right and that the pure-Python path can't offer on its own,
+// since `PickleBuffer` has to wrap an existing object's buffer without
+// copying it) become available natively. A full native Pickler/Unpickler is
+// a much larger, opcode-by-opcode port that belongs in its own change.
+#[pymodule]
+mod _pickle {
+    use crate::{
+        common::lock::PyMutex,
+        vm::{
+            PyObjectRef, PyPayload, PyResult, TryFromBorrowedObject, VirtualMachine,
+            builtins::{PyMemoryView, PyTypeRef},
+            protocol::{BufferDescriptor, PyBuffer},
+            types::Constructor,
+        },
+    };
+
+    /// The native counterpart to `pickle.PickleBuffer`: a zero-copy wrapper
+    /// around any object supporting the buffer protocol, used to carry
+    /// protocol 5's out-of-band buffers. See `Lib/pickle.py`'s
+    /// `Pickler.save_picklebuffer`/`Unpickler.load_next_buffer` for how the
+    /// pure-Python (de)serializer drives this type. Holding the `PyBuffer`
+    /// for the object's lifetime (rather than re-acquiring it on each call)
+    /// keeps the wrapped object's exports count raised the whole time, the
+    /// same guarantee a real buffer/memoryview export gives -- e.g. it
+    /// blocks `bytearray.resize` on the wrapped object until `release()`.
+    #[pyattr]
+    #[pyclass(module = "_pickle", name = "PickleBuffer")]
+    #[derive(Debug, PyPayload)]
+    struct PickleBuffer {
+        buffer: PyMutex<Option<PyBuffer>>,
+    }
+
+    impl Constructor for PickleBuffer {
+        type Args = PyObjectRef;
+
+        fn py_new(cls: PyTypeRef, obj: Self::Args, vm: &VirtualMachine) -> PyResult {
+            let buffer = PyBuffer::try_from_borrowed_object(vm, &obj)?;
+            Self {
+                buffer: PyMutex::new(Some(buffer)),
+            }
+            .into_ref_with_type(vm, cls)
+            .map(Into::into)
+        }
+    }
+
+    #[pyclass(with(Constructor))]
+    impl PickleBuffer {
+        fn with_buffer<R>(
+            &self,
+            vm: &VirtualMachine,
+            f: impl FnOnce(&PyBuffer) -> PyResult<R>,
+        ) -> PyResult<R> {
+            match &*self.buffer.lock() {
+                Some(buffer) => f(buffer),
+                None => {
+                    Err(vm.new_value_error("operation forbidden on released PickleBuffer object"))
+                }
+            }
+        }
+
+        /// Return a flat, one-dimensional `memoryview` (format `B`) of the
+        /// raw bytes backing this buffer, same as `_pickle.c`'s
+        /// `PickleBuffer.raw`. Raises if the underlying buffer isn't
+        /// C-contiguous, since there'd be no single flat byte run to expose.
+        #[pymethod]
+        fn raw(&self, vm: &VirtualMachine) -> PyResult {
+            self.with_buffer(vm, |buffer| {
+                if !buffer.desc.is_contiguous() {
+                    return Err(vm.new_buffer_error("PickleBuffer is not C-contiguous"));
+                }
+                let mut flat = buffer.clone();
+                flat.retain();
+                flat.desc = BufferDescriptor::simple(flat.desc.len, flat.desc.readonly);
+                Ok(PyMemoryView::from_buffer(flat, vm)?.into_pyobject(vm))
+            })
+        }
+
+        /// Release the underlying buffer, as `memoryview.release()` does.
+        /// Idempotent: releasing an already-released `PickleBuffer` is a
+        /// no-op rather than an error.
+        #[pymethod]
+        fn release(&self) {
+            self.buffer.lock().take();
+        }
+    }
+}
@@ -0,0 +1,42 @@
+// spell-checker:disable
+
+pub(crate) use _posixshmem::make_module;
+
+#[pymodule]
+mod _posixshmem {
+    use crate::vm::{PyResult, VirtualMachine, builtins::PyStrRef, stdlib::os};
+    use std::ffi::CString;
+
+    #[derive(FromArgs)]
+    struct ShmOpenArgs {
+        #[pyarg(any)]
+        path: PyStrRef,
+        #[pyarg(any)]
+        flags: libc::c_int,
+        #[pyarg(any, default = 0o777)]
+        mode: libc::mode_t,
+    }
+
+    #[pyfunction]
+    fn shm_open(args: ShmOpenArgs, vm: &VirtualMachine) -> PyResult<i32> {
+        let cpath = CString::new(args.path.as_str())
+            .map_err(|_| vm.new_value_error("embedded null byte"))?;
+        let fd = unsafe { libc::shm_open(cpath.as_ptr(), args.flags, args.mode) };
+        if fd < 0 {
+            Err(os::errno_err(vm))
+        } else {
+            Ok(fd)
+        }
+    }
+
+    #[pyfunction]
+    fn shm_unlink(path: PyStrRef, vm: &VirtualMachine) -> PyResult<()> {
+        let cpath =
+            CString::new(path.as_str()).map_err(|_| vm.new_value_error("embedded null byte"))?;
+        if unsafe { libc::shm_unlink(cpath.as_ptr()) } != 0 {
+            Err(os::errno_err(vm))
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -48,9 +48,22 @@ mod _posixsubprocess {
             envp: envp.as_deref(),
             extra_groups: extra_groups.as_deref(),
         };
-        match unsafe { nix::unistd::fork() }.map_err(|err| err.into_pyexception(vm))? {
-            nix::unistd::ForkResult::Child => exec(&args, procargs),
-            nix::unistd::ForkResult::Parent { child } => Ok(child.as_raw()),
+        // run os.register_at_fork(before=...) hooks so threads can release
+        // locks that would otherwise come out of the fork stuck forever in
+        // the child; mirrors what CPython's _posixsubprocess.c does.
+        posix::py_os_before_fork(vm);
+        match unsafe { nix::unistd::fork() } {
+            Ok(nix::unistd::ForkResult::Child) => exec(&args, procargs),
+            Ok(nix::unistd::ForkResult::Parent { child }) => {
+                // the child execs (or _exits) without ever returning here, so
+                // only the parent side runs after_in_parent hooks.
+                posix::py_os_after_fork_parent(vm);
+                Ok(child.as_raw())
+            }
+            Err(err) => {
+                posix::py_os_after_fork_parent(vm);
+                Err(err.into_pyexception(vm))
+            }
         }
     }
 }
@@ -319,7 +332,15 @@ fn exec_inner(
     }
 
     if args.restore_signals {
-        // TODO: restore signals SIGPIPE, SIGXFZ, SIGXFSZ to SIG_DFL
+        // mirrors CPython's _Py_RestoreSignals(): put back the handlers the
+        // interpreter set to SIG_IGN on startup, since the child didn't ask
+        // to inherit them. (CPython also resets SIGXFZ, but that's an old
+        // IRIX-only signal with no equivalent on our supported platforms.)
+        use nix::sys::signal::{SaFlags, SigAction, SigHandler, SigSet, Signal, sigaction};
+        let action = SigAction::new(SigHandler::SigDfl, SaFlags::empty(), SigSet::empty());
+        for signal in [Signal::SIGPIPE, Signal::SIGXFSZ] {
+            unsafe { sigaction(signal, &action) }?;
+        }
     }
 
     if args.call_setsid {
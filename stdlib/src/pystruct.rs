@@ -9,6 +9,7 @@ pub(crate) use _struct::make_module;
 
 #[pymodule]
 pub(crate) mod _struct {
+    use crate::common::lock::PyMutex;
     use crate::vm::{
         AsObject, Py, PyObjectRef, PyPayload, PyResult, TryFromObject, VirtualMachine,
         buffer::{FormatSpec, new_struct_error, struct_error_type},
@@ -19,6 +20,8 @@ pub(crate) mod _struct {
         types::{Constructor, IterNext, Iterable, Representable, SelfIter, Unconstructible},
     };
     use crossbeam_utils::atomic::AtomicCell;
+    use std::collections::HashMap;
+    use std::sync::{Arc, LazyLock};
 
     #[derive(Traverse)]
     struct IntoStructFormatBytes(PyStrRef);
@@ -43,9 +46,33 @@ pub(crate) mod _struct {
         }
     }
 
+    /// Caps the `FormatSpec` cache the same way CPython's `_struct` module
+    /// caps `_structmodulestate.cache`: once it grows past this many
+    /// entries, it's simply dropped and starts over, rather than maintaining
+    /// an LRU eviction order.
+    const FORMAT_CACHE_SIZE: usize = 100;
+    static FORMAT_CACHE: LazyLock<PyMutex<HashMap<Vec<u8>, Arc<FormatSpec>>>> =
+        LazyLock::new(|| PyMutex::new(HashMap::new()));
+
     impl IntoStructFormatBytes {
-        fn format_spec(&self, vm: &VirtualMachine) -> PyResult<FormatSpec> {
-            FormatSpec::parse(self.0.as_bytes(), vm)
+        /// Parsing a format string into a `FormatSpec` is pure work off of
+        /// the format bytes alone, so -- like CPython's `_struct` module --
+        /// cache the result keyed by the format string: code that calls the
+        /// free `struct.pack`/`struct.unpack` functions repeatedly with the
+        /// same literal format (rather than precompiling a `Struct` object)
+        /// shouldn't pay to re-parse it every time.
+        fn format_spec(&self, vm: &VirtualMachine) -> PyResult<Arc<FormatSpec>> {
+            let key = self.0.as_bytes();
+            if let Some(spec) = FORMAT_CACHE.lock().get(key) {
+                return Ok(spec.clone());
+            }
+            let spec = Arc::new(FormatSpec::parse(key, vm)?);
+            let mut cache = FORMAT_CACHE.lock();
+            if cache.len() >= FORMAT_CACHE_SIZE {
+                cache.clear();
+            }
+            cache.insert(key.to_vec(), spec.clone());
+            Ok(spec)
         }
     }
 
@@ -154,7 +181,7 @@ pub(crate) mod _struct {
     #[derive(Debug, PyPayload)]
     struct UnpackIterator {
         #[pytraverse(skip)]
-        format_spec: FormatSpec,
+        format_spec: Arc<FormatSpec>,
         buffer: ArgBytesLike,
         #[pytraverse(skip)]
         offset: AtomicCell<usize>,
@@ -163,7 +190,7 @@ pub(crate) mod _struct {
     impl UnpackIterator {
         fn with_buffer(
             vm: &VirtualMachine,
-            format_spec: FormatSpec,
+            format_spec: Arc<FormatSpec>,
             buffer: ArgBytesLike,
         ) -> PyResult<Self> {
             if format_spec.size == 0 {
@@ -234,7 +261,7 @@ pub(crate) mod _struct {
     #[derive(Debug, PyPayload)]
     struct PyStruct {
         #[pytraverse(skip)]
-        spec: FormatSpec,
+        spec: Arc<FormatSpec>,
         format: PyStrRef,
     }
 
@@ -313,9 +340,10 @@ pub(crate) mod _struct {
     }
 
     // seems weird that this is part of the "public" API, but whatever
-    // TODO: implement a format code->spec cache like CPython does?
     #[pyfunction]
-    const fn _clearcache() {}
+    fn _clearcache() {
+        FORMAT_CACHE.lock().clear();
+    }
 
     #[pyattr(name = "error")]
     fn error_type(vm: &VirtualMachine) -> PyTypeRef {
@@ -7,6 +7,7 @@ mod resource {
     use crate::vm::{
         PyObject, PyObjectRef, PyResult, TryFromBorrowedObject, VirtualMachine,
         convert::{ToPyException, ToPyObject},
+        function::OptionalArg,
         stdlib::os,
         types::PyStructSequence,
     };
@@ -189,4 +190,37 @@ mod resource {
             _ => e.to_pyexception(vm),
         })
     }
+
+    #[pyfunction]
+    fn getpagesize() -> i32 {
+        unsafe { libc::getpagesize() }
+    }
+
+    // prlimit(2) is Linux-specific; other unixes only expose get/setrlimit for the calling
+    // process itself.
+    #[cfg(target_os = "linux")]
+    #[pyfunction]
+    fn prlimit(
+        pid: libc::pid_t,
+        resource: i32,
+        new_limits: OptionalArg<Limits>,
+        vm: &VirtualMachine,
+    ) -> PyResult<Limits> {
+        #[allow(clippy::unnecessary_cast)]
+        if resource < 0 || resource >= RLIM_NLIMITS as i32 {
+            return Err(vm.new_value_error("invalid resource specified"));
+        }
+        let new = new_limits.into_option().map(|l| l.0);
+        let new_ptr = new
+            .as_ref()
+            .map_or(std::ptr::null(), |l| l as *const libc::rlimit);
+        let old = unsafe {
+            let mut old = mem::MaybeUninit::<libc::rlimit>::uninit();
+            if libc::prlimit(pid, resource as _, new_ptr, old.as_mut_ptr()) == -1 {
+                return Err(os::errno_err(vm));
+            }
+            old.assume_init()
+        };
+        Ok(Limits(old))
+    }
 }
@@ -542,6 +542,368 @@ mod decl {
     #[pyattr]
     const EPOLLET: u32 = libc::EPOLLET as u32;
 
+    // illumos/Solaris's `/dev/poll` interface has no Rust crate support in this
+    // project's dependency tree (it isn't exposed by rustix or libc in a portable
+    // way), and none of our supported targets are illumos/Solaris, so there's no
+    // native `select.devpoll` here -- unlike `poll`/`epoll` above and `kqueue`
+    // below.
+
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly"
+    ))]
+    #[pyattr(name = "kqueue", once)]
+    fn kqueue(vm: &VirtualMachine) -> PyTypeRef {
+        use crate::vm::class::PyClassImpl;
+        kqueue::PyKqueue::make_class(&vm.ctx)
+    }
+
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly"
+    ))]
+    #[pyattr(name = "kevent", once)]
+    fn kevent_type(vm: &VirtualMachine) -> PyTypeRef {
+        use crate::vm::class::PyClassImpl;
+        kqueue::PyKevent::make_class(&vm.ctx)
+    }
+
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly"
+    ))]
+    #[pyattr]
+    use libc::{
+        EV_ADD, EV_CLEAR, EV_DELETE, EV_DISABLE, EV_ENABLE, EV_EOF, EV_ERROR, EV_ONESHOT,
+        EVFILT_PROC, EVFILT_READ, EVFILT_SIGNAL, EVFILT_TIMER, EVFILT_VNODE, EVFILT_WRITE,
+        NOTE_DELETE, NOTE_EXIT, NOTE_EXTEND, NOTE_FORK, NOTE_LINK, NOTE_RENAME, NOTE_REVOKE,
+        NOTE_WRITE,
+    };
+
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly"
+    ))]
+    pub(super) mod kqueue {
+        use super::*;
+        use crate::vm::{
+            AsObject, Py, PyPayload,
+            common::lock::{PyRwLock, PyRwLockReadGuard},
+            convert::IntoPyException,
+            function::{OptionalArg, PyComparisonValue},
+            stdlib::io::Fildes,
+            types::{Comparable, Constructor, PyComparisonOp, Representable},
+        };
+        use num_traits::ToPrimitive;
+        use std::ops::Deref;
+        use std::os::fd::{AsRawFd, FromRawFd, IntoRawFd, OwnedFd};
+        use std::time::Instant;
+
+        #[pyclass(module = "select", name = "kqueue")]
+        #[derive(Debug, PyPayload)]
+        pub struct PyKqueue {
+            kqueue_fd: PyRwLock<Option<OwnedFd>>,
+        }
+
+        impl Constructor for PyKqueue {
+            type Args = ();
+            fn py_new(cls: PyTypeRef, _: Self::Args, vm: &VirtualMachine) -> PyResult {
+                Self::new()
+                    .map_err(|e| e.into_pyexception(vm))?
+                    .into_ref_with_type(vm, cls)
+                    .map(Into::into)
+            }
+        }
+
+        #[derive(FromArgs)]
+        struct KqueueControlArgs {
+            #[pyarg(any)]
+            changelist: PyObjectRef,
+            #[pyarg(any)]
+            max_events: i32,
+            #[pyarg(any, default)]
+            timeout: poll::TimeoutArg<false>,
+        }
+
+        #[pyclass(with(Constructor))]
+        impl PyKqueue {
+            fn new() -> std::io::Result<Self> {
+                let fd = unsafe { libc::kqueue() };
+                if fd < 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                let kqueue_fd = unsafe { OwnedFd::from_raw_fd(fd) };
+                Ok(Self {
+                    kqueue_fd: Some(kqueue_fd).into(),
+                })
+            }
+
+            #[pymethod]
+            fn close(&self) -> std::io::Result<()> {
+                let fd = self.kqueue_fd.write().take();
+                if let Some(fd) = fd {
+                    nix::unistd::close(fd.into_raw_fd())?;
+                }
+                Ok(())
+            }
+
+            #[pygetset]
+            fn closed(&self) -> bool {
+                self.kqueue_fd.read().is_none()
+            }
+
+            fn get_kqueue(
+                &self,
+                vm: &VirtualMachine,
+            ) -> PyResult<impl Deref<Target = OwnedFd> + '_> {
+                PyRwLockReadGuard::try_map(self.kqueue_fd.read(), |x| x.as_ref())
+                    .map_err(|_| vm.new_value_error("I/O operation on closed kqueue object"))
+            }
+
+            #[pymethod]
+            fn fileno(&self, vm: &VirtualMachine) -> PyResult<i32> {
+                self.get_kqueue(vm).map(|fd| fd.as_raw_fd())
+            }
+
+            #[pyclassmethod]
+            fn fromfd(cls: PyTypeRef, fd: OwnedFd, vm: &VirtualMachine) -> PyResult<PyRef<Self>> {
+                let kqueue_fd = Some(fd).into();
+                Self { kqueue_fd }.into_ref_with_type(vm, cls)
+            }
+
+            #[pymethod]
+            fn control(&self, args: KqueueControlArgs, vm: &VirtualMachine) -> PyResult<Vec<PyObjectRef>> {
+                let changelist = if vm.is_none(&args.changelist) {
+                    Vec::new()
+                } else {
+                    let events: Vec<PyRef<PyKevent>> = args.changelist.try_to_value(vm)?;
+                    events.iter().map(|e| e.as_libc_kevent()).collect::<Vec<_>>()
+                };
+
+                if args.max_events < 0 {
+                    return Err(vm.new_value_error("Length must be nonnegative."));
+                }
+
+                let poll::TimeoutArg(timeout) = args.timeout;
+                let deadline = timeout.map(|d| Instant::now() + d);
+                let mut poll_timeout = timeout.map(|d| libc::timespec {
+                    tv_sec: d.as_secs() as _,
+                    tv_nsec: d.subsec_nanos() as _,
+                });
+
+                let mut eventlist = vec![unsafe { std::mem::zeroed::<libc::kevent>() }; args.max_events as usize];
+                let kqueue_fd = self.get_kqueue(vm)?.as_raw_fd();
+
+                let n = loop {
+                    let ts_ptr = poll_timeout
+                        .as_mut()
+                        .map_or(std::ptr::null(), |ts| ts as *const _);
+                    let res = unsafe {
+                        libc::kevent(
+                            kqueue_fd,
+                            changelist.as_ptr(),
+                            changelist.len() as i32,
+                            eventlist.as_mut_ptr(),
+                            eventlist.len() as i32,
+                            ts_ptr,
+                        )
+                    };
+                    if res >= 0 {
+                        break res;
+                    }
+                    let err = std::io::Error::last_os_error();
+                    if err.kind() == std::io::ErrorKind::Interrupted {
+                        vm.check_signals()?;
+                    } else {
+                        return Err(err.into_pyexception(vm));
+                    }
+                    if let Some(deadline) = deadline {
+                        match deadline.checked_duration_since(Instant::now()) {
+                            Some(remaining) => {
+                                poll_timeout = Some(libc::timespec {
+                                    tv_sec: remaining.as_secs() as _,
+                                    tv_nsec: remaining.subsec_nanos() as _,
+                                });
+                            }
+                            None => break 0,
+                        }
+                    }
+                };
+
+                eventlist.truncate(n.max(0) as usize);
+                Ok(eventlist
+                    .into_iter()
+                    .map(|ev| PyKevent::from_libc_kevent(ev).into_ref(&vm.ctx).into())
+                    .collect())
+            }
+
+            #[pymethod]
+            fn __enter__(zelf: PyRef<Self>, vm: &VirtualMachine) -> PyResult<PyRef<Self>> {
+                zelf.get_kqueue(vm)?;
+                Ok(zelf)
+            }
+
+            #[pymethod]
+            fn __exit__(
+                &self,
+                _exc_type: OptionalArg,
+                _exc_value: OptionalArg,
+                _exc_tb: OptionalArg,
+            ) -> std::io::Result<()> {
+                self.close()
+            }
+        }
+
+        #[pyclass(module = "select", name = "kevent")]
+        #[derive(Debug, Copy, Clone, PyPayload)]
+        pub struct PyKevent {
+            ident: libc::uintptr_t,
+            filter: i16,
+            flags: u16,
+            fflags: u32,
+            data: libc::intptr_t,
+            udata: libc::intptr_t,
+        }
+
+        #[derive(FromArgs)]
+        pub struct KeventNewArgs {
+            #[pyarg(any)]
+            ident: PyObjectRef,
+            #[pyarg(any, default = "libc::EVFILT_READ as i16")]
+            filter: i16,
+            #[pyarg(any, default = "libc::EV_ADD as u16")]
+            flags: u16,
+            #[pyarg(any, default = 0)]
+            fflags: u32,
+            #[pyarg(any, default = 0)]
+            data: libc::intptr_t,
+            #[pyarg(any, default = 0)]
+            udata: libc::intptr_t,
+        }
+
+        impl Constructor for PyKevent {
+            type Args = KeventNewArgs;
+            fn py_new(cls: PyTypeRef, args: KeventNewArgs, vm: &VirtualMachine) -> PyResult {
+                let ident = match args.ident.try_to_value::<Fildes>(vm) {
+                    Ok(Fildes(fd)) => fd as libc::uintptr_t,
+                    Err(_) => args
+                        .ident
+                        .try_int(vm)?
+                        .as_bigint()
+                        .to_usize()
+                        .ok_or_else(|| vm.new_overflow_error("value out of range"))?
+                        as libc::uintptr_t,
+                };
+                Self {
+                    ident,
+                    filter: args.filter,
+                    flags: args.flags,
+                    fflags: args.fflags,
+                    data: args.data,
+                    udata: args.udata,
+                }
+                .into_ref_with_type(vm, cls)
+                .map(Into::into)
+            }
+        }
+
+        #[pyclass(with(Constructor, Comparable, Representable))]
+        impl PyKevent {
+            fn as_libc_kevent(&self) -> libc::kevent {
+                libc::kevent {
+                    ident: self.ident,
+                    filter: self.filter,
+                    flags: self.flags,
+                    fflags: self.fflags,
+                    data: self.data,
+                    udata: self.udata as *mut libc::c_void,
+                }
+            }
+
+            fn from_libc_kevent(ev: libc::kevent) -> Self {
+                Self {
+                    ident: ev.ident,
+                    filter: ev.filter,
+                    flags: ev.flags,
+                    fflags: ev.fflags,
+                    data: ev.data,
+                    udata: ev.udata as libc::intptr_t,
+                }
+            }
+
+            fn as_tuple(&self) -> (libc::uintptr_t, i16, u16, u32, libc::intptr_t, libc::intptr_t) {
+                (self.ident, self.filter, self.flags, self.fflags, self.data, self.udata)
+            }
+
+            #[pygetset]
+            fn ident(&self) -> libc::uintptr_t {
+                self.ident
+            }
+
+            #[pygetset]
+            fn filter(&self) -> i16 {
+                self.filter
+            }
+
+            #[pygetset]
+            fn flags(&self) -> u16 {
+                self.flags
+            }
+
+            #[pygetset]
+            fn fflags(&self) -> u32 {
+                self.fflags
+            }
+
+            #[pygetset]
+            fn data(&self) -> libc::intptr_t {
+                self.data
+            }
+
+            #[pygetset]
+            fn udata(&self) -> libc::intptr_t {
+                self.udata
+            }
+        }
+
+        impl Comparable for PyKevent {
+            fn cmp(
+                zelf: &Py<Self>,
+                other: &PyObject,
+                op: PyComparisonOp,
+                vm: &VirtualMachine,
+            ) -> PyResult<PyComparisonValue> {
+                let other = class_or_notimplemented!(Self, other);
+                Ok(op.eval_ord(zelf.as_tuple().cmp(&other.as_tuple())).into())
+            }
+        }
+
+        impl Representable for PyKevent {
+            fn repr_str(zelf: &Py<Self>, _vm: &VirtualMachine) -> PyResult<String> {
+                Ok(format!(
+                    "<select.kevent ident={} filter={} flags={} fflags={} data={} udata={}>",
+                    zelf.ident, zelf.filter, zelf.flags, zelf.fflags, zelf.data, zelf.udata
+                ))
+            }
+        }
+    }
+
     #[cfg(any(target_os = "linux", target_os = "android", target_os = "redox"))]
     pub(super) mod epoll {
         use super::*;
@@ -1446,6 +1446,87 @@ mod _socket {
             Ok(cmsg_buffer)
         }
 
+        #[cfg(all(unix, not(target_os = "redox")))]
+        #[pymethod]
+        fn recvmsg(
+            &self,
+            bufsize: isize,
+            ancbufsize: OptionalArg<isize>,
+            flags: OptionalArg<i32>,
+            vm: &VirtualMachine,
+        ) -> Result<(Vec<u8>, Vec<PyObjectRef>, i32, PyObjectRef), IoOrPyException> {
+            let bufsize = bufsize
+                .to_usize()
+                .ok_or_else(|| vm.new_value_error("negative buffersize in recvmsg"))?;
+            let ancbufsize = ancbufsize
+                .unwrap_or(0)
+                .to_usize()
+                .ok_or_else(|| vm.new_value_error("negative ancillary buffer size"))?;
+            checked_cmsg_space(ancbufsize)
+                .ok_or_else(|| vm.new_overflow_error("ancillary buffer size is too large"))?;
+            let flags = flags.unwrap_or(0);
+
+            let fd = sock_fileno(&self.sock()?);
+            let mut buffer = vec![0u8; bufsize];
+            let mut control_buf = vec![0u8; ancbufsize];
+            let mut addr_storage = unsafe { std::mem::zeroed::<libc::sockaddr_storage>() };
+
+            let (n, msg_flags, ancdata, addr_len) = self.sock_op(vm, SelectKind::Read, || {
+                let mut iov = libc::iovec {
+                    iov_base: buffer.as_mut_ptr().cast(),
+                    iov_len: buffer.len(),
+                };
+                let mut mhdr: libc::msghdr = unsafe { std::mem::zeroed() };
+                mhdr.msg_name = std::ptr::addr_of_mut!(addr_storage).cast();
+                mhdr.msg_namelen = std::mem::size_of::<libc::sockaddr_storage>() as _;
+                mhdr.msg_iov = &mut iov;
+                mhdr.msg_iovlen = 1;
+                if !control_buf.is_empty() {
+                    mhdr.msg_control = control_buf.as_mut_ptr().cast();
+                    mhdr.msg_controllen = control_buf.len() as _;
+                }
+
+                let n = unsafe { libc::recvmsg(fd as _, &mut mhdr, flags) };
+                if n < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+
+                Ok((
+                    n as usize,
+                    mhdr.msg_flags,
+                    Self::unpack_cmsgs(&mhdr),
+                    mhdr.msg_namelen,
+                ))
+            })?;
+            buffer.truncate(n);
+
+            let ancdata = ancdata
+                .into_iter()
+                .map(|(level, typ, data)| vm.new_tuple((level, typ, data)).into())
+                .collect();
+
+            let addr = unsafe { socket2::SockAddr::new(addr_storage, addr_len) };
+            Ok((buffer, ancdata, msg_flags, get_addr_tuple(&addr, vm)))
+        }
+
+        // based on nix's implementation
+        #[cfg(all(unix, not(target_os = "redox")))]
+        fn unpack_cmsgs(mhdr: &libc::msghdr) -> Vec<(i32, i32, Vec<u8>)> {
+            let mut result = Vec::new();
+            unsafe {
+                let mut pmhdr = libc::CMSG_FIRSTHDR(mhdr);
+                while !pmhdr.is_null() {
+                    let cmsg_len = (*pmhdr).cmsg_len as usize;
+                    let data_len = cmsg_len.saturating_sub(libc::CMSG_LEN(0) as usize);
+                    let data =
+                        std::slice::from_raw_parts(libc::CMSG_DATA(pmhdr), data_len).to_vec();
+                    result.push(((*pmhdr).cmsg_level, (*pmhdr).cmsg_type, data));
+                    pmhdr = libc::CMSG_NXTHDR(mhdr, pmhdr);
+                }
+            }
+            result
+        }
+
         #[pymethod]
         fn close(&self) -> io::Result<()> {
             let sock = self.detach();
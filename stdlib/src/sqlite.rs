@@ -143,6 +143,25 @@ mod _sqlite {
         ptr_to_str(s, vm).unwrap().to_owned()
     }
 
+    #[pyattr]
+    fn sqlite_version_info(vm: &VirtualMachine) -> (i32, i32, i32) {
+        let s = unsafe { sqlite3_libversion() };
+        let version = ptr_to_str(s, vm).unwrap();
+        let mut parts = version.split('.').map(|p| p.parse().unwrap_or(0));
+        (
+            parts.next().unwrap_or(0),
+            parts.next().unwrap_or(0),
+            parts.next().unwrap_or(0),
+        )
+    }
+
+    // DB-API 2.0 module attributes (PEP 249); paramstyle matches the "?"
+    // placeholders `Statement::bind` expects.
+    #[pyattr(name = "apilevel")]
+    const APILEVEL: &str = "2.0";
+    #[pyattr(name = "paramstyle")]
+    const PARAMSTYLE: &str = "qmark";
+
     #[pyattr]
     fn threadsafety(_: &VirtualMachine) -> c_int {
         let mode = unsafe { sqlite3_threadsafe() };
@@ -163,25 +163,50 @@ mod _ssl {
     #[pyattr]
     const HAS_TLSv1_3: bool = cfg!(ossl111);
 
-    // the openssl version from the API headers
+    // The openssl version from the API headers. Nothing here is expensive to compute, but
+    // every embedder pays for it at `_ssl` module init whether or not their program ever
+    // looks at these attributes, so compute them lazily via module `__getattr__` on first
+    // access instead (mirrors the `posix`/`nt` treatment of `environ`).
+    const LAZY_VERSION_ATTRS: &[&str] = &[
+        "OPENSSL_VERSION",
+        "OPENSSL_VERSION_NUMBER",
+        "OPENSSL_VERSION_INFO",
+        "_OPENSSL_API_VERSION",
+    ];
 
-    #[pyattr(name = "OPENSSL_VERSION")]
-    fn openssl_version(_vm: &VirtualMachine) -> &str {
-        openssl::version::version()
-    }
-    #[pyattr(name = "OPENSSL_VERSION_NUMBER")]
-    fn openssl_version_number(_vm: &VirtualMachine) -> i64 {
-        openssl::version::number()
-    }
-    #[pyattr(name = "OPENSSL_VERSION_INFO")]
-    fn openssl_version_info(_vm: &VirtualMachine) -> OpensslVersionInfo {
-        parse_version_info(openssl::version::number())
+    #[pyfunction]
+    fn __getattr__(name: PyStrRef, vm: &VirtualMachine) -> PyResult {
+        let value = match name.as_str() {
+            "OPENSSL_VERSION" => vm.new_pyobj(openssl::version::version()),
+            "OPENSSL_VERSION_NUMBER" => vm.new_pyobj(openssl::version::number()),
+            "OPENSSL_VERSION_INFO" => vm.new_pyobj(parse_version_info(openssl::version::number())),
+            "_OPENSSL_API_VERSION" => {
+                let openssl_api_version =
+                    i64::from_str_radix(env!("OPENSSL_API_VERSION"), 16).unwrap();
+                vm.new_pyobj(parse_version_info(openssl_api_version))
+            }
+            _ => {
+                return Err(vm.new_attribute_error(format!("module 'ssl' has no attribute '{name}'")));
+            }
+        };
+        // Cache into the module dict so repeated access is a plain dict lookup and this
+        // `__getattr__` isn't invoked again for the same name.
+        let module = vm.import("_ssl", 0)?;
+        if let Some(dict) = module.dict() {
+            dict.set_item(name.as_str(), value.clone(), vm)?;
+        }
+        Ok(value)
     }
 
-    #[pyattr(name = "_OPENSSL_API_VERSION")]
-    fn _openssl_api_version(_vm: &VirtualMachine) -> OpensslVersionInfo {
-        let openssl_api_version = i64::from_str_radix(env!("OPENSSL_API_VERSION"), 16).unwrap();
-        parse_version_info(openssl_api_version)
+    #[pyfunction]
+    fn __dir__(vm: &VirtualMachine) -> PyResult<Vec<PyObjectRef>> {
+        let module = vm.import("_ssl", 0)?;
+        let dict = module
+            .dict()
+            .ok_or_else(|| vm.new_type_error("_ssl module has no dict"))?;
+        let mut attrs: Vec<PyObjectRef> = dict.into_iter().map(|(k, _v)| k).collect();
+        attrs.extend(LAZY_VERSION_ATTRS.iter().map(|&name| vm.ctx.new_str(name).into()));
+        Ok(attrs)
     }
 
     /// An error occurred in the SSL implementation.
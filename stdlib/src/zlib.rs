@@ -404,13 +404,27 @@ mod zlib {
             self.inner.lock().flush(mode, vm)
         }
 
-        // TODO: This is an optional feature of Compress
-        // #[pymethod]
-        // #[pymethod(name = "__copy__")]
-        // #[pymethod(name = "__deepcopy__")]
-        // fn copy(&self) -> Self {
-        //     todo!("<flate2::Compress as Clone>")
-        // }
+        #[pymethod]
+        fn copy(&self, vm: &VirtualMachine) -> PyResult<Self> {
+            // CPython duplicates the in-progress zlib stream state with
+            // deflateCopy(), but the flate2 crate doesn't expose stream
+            // duplication, so there's no way to fork an in-flight Compress
+            // object here.
+            Err(new_zlib_error(
+                "Compress.copy() is not supported by this zlib backend",
+                vm,
+            ))
+        }
+
+        #[pymethod(name = "__copy__")]
+        fn dunder_copy(&self, vm: &VirtualMachine) -> PyResult<Self> {
+            self.copy(vm)
+        }
+
+        #[pymethod(name = "__deepcopy__")]
+        fn dunder_deepcopy(&self, _memo: PyObjectRef, vm: &VirtualMachine) -> PyResult<Self> {
+            self.copy(vm)
+        }
     }
 
     const CHUNKSIZE: usize = u32::MAX as usize;
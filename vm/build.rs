@@ -36,6 +36,46 @@ fn main() {
         std::env::vars_os().format_with(", ", |(k, v), f| f(&format_args!("{k:?} => {v:?}")))
     )
     .unwrap();
+
+    let mut names_path = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    names_path.push("stdlib_module_names.rs");
+    let mut f = std::fs::File::create(names_path).unwrap();
+    write!(
+        f,
+        "&[{}]",
+        pure_python_stdlib_module_names()
+            .iter()
+            .format_with(", ", |name, f| f(&format_args!("{name:?}")))
+    )
+    .unwrap();
+}
+
+/// Top-level module/package names under `Lib/`, modelled on how CPython
+/// generates `Python/stdlib_module_names.h` from its own `Lib/` listing:
+/// every `*.py` file and every subdirectory is a module or package, except
+/// the directories in `EXCLUDED_DIRS` (the test suite and the bundled
+/// tools `idlelib`, `turtledemo`, `ensurepip` and `venv`).
+fn pure_python_stdlib_module_names() -> Vec<String> {
+    const EXCLUDED_DIRS: &[&str] = &["test", "idlelib", "turtledemo", "ensurepip", "venv"];
+
+    println!("cargo:rerun-if-changed=../Lib");
+    let mut names = vec![];
+    for entry in std::fs::read_dir("../Lib").expect("Lib/ exists?").flatten() {
+        let path = entry.path();
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        if path.is_dir() {
+            if !EXCLUDED_DIRS.contains(&stem) {
+                names.push(stem.to_owned());
+            }
+        } else if path.extension().is_some_and(|ext| ext == "py") && stem != "__init__" {
+            names.push(stem.to_owned());
+        }
+    }
+    names.sort();
+    names.dedup();
+    names
 }
 
 fn git_hash() -> String {
@@ -253,6 +253,12 @@ pub trait AnyStr {
         }
     }
 
+    /// The `find` closure callers pass in does the real substring search --
+    /// for both `str` (via `Wtf8::find`) and `bytes`/`bytearray` (via
+    /// `bstr`'s `ByteSlice::find`) that's backed by `memchr::memmem`, so
+    /// `find`/`index`/`split`/`replace`/`in` all share the same
+    /// SIMD-accelerated scan under the hood; this just adjusts for the
+    /// caller-supplied search range.
     #[inline]
     fn py_find<F>(&self, needle: &Self, range: std::ops::Range<usize>, find: F) -> Option<usize>
     where
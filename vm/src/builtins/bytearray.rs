@@ -422,6 +422,10 @@ impl PyByteArray {
         index.ok_or_else(|| vm.new_value_error("substring not found"))
     }
 
+    // Like CPython, this always builds a new bytearray rather than mutating
+    // `self` -- there's no in-place `translate` in the data model, since the
+    // delete argument can shrink the result. The table/delete-set lookups
+    // themselves are still O(1) per byte; see `ByteInnerTranslateOptions`.
     #[pymethod]
     fn translate(&self, options: ByteInnerTranslateOptions, vm: &VirtualMachine) -> PyResult<Self> {
         Ok(self.inner().translate(options, vm)?.into())
@@ -264,15 +264,46 @@ impl ConstantBag for PyObjBag<'_> {
                 ctx.intern_str(value).to_object()
             }
             BorrowedConstant::Str { value } => ctx.new_str(value).into(),
-            BorrowedConstant::Bytes { value } => ctx.new_bytes(value.to_vec()).into(),
+            BorrowedConstant::Bytes { .. } => {
+                let key = constant.to_owned();
+                if let Some(cached) = ctx.bytes_const_pool.lock().get(&key) {
+                    return Literal(cached.clone().into());
+                }
+                let BorrowedConstant::Bytes { value } = constant else {
+                    unreachable!()
+                };
+                let bytes = ctx.new_bytes(value.to_vec());
+                ctx.bytes_const_pool
+                    .lock()
+                    .entry(key)
+                    .or_insert(bytes)
+                    .clone()
+                    .into()
+            }
             BorrowedConstant::Boolean { value } => ctx.new_bool(value).into(),
             BorrowedConstant::Code { code } => ctx.new_code(code.map_clone_bag(self)).into(),
-            BorrowedConstant::Tuple { elements } => {
-                let elements = elements
+            BorrowedConstant::Tuple { .. } => {
+                // Dedup by structural content so e.g. the same small tuple
+                // literal compiled into several code objects (or reloaded
+                // via `marshal`) ends up sharing a single PyTuple.
+                let key = constant.to_owned();
+                if let Some(cached) = ctx.tuple_const_pool.lock().get(&key) {
+                    return Literal(cached.clone().into());
+                }
+                let BorrowedConstant::Tuple { elements } = constant else {
+                    unreachable!()
+                };
+                let built_elements = elements
                     .iter()
                     .map(|constant| self.make_constant(constant.borrow_constant()).0)
                     .collect();
-                ctx.new_tuple(elements).into()
+                let tuple = ctx.new_tuple(built_elements);
+                ctx.tuple_const_pool
+                    .lock()
+                    .entry(key)
+                    .or_insert(tuple)
+                    .clone()
+                    .into()
             }
             BorrowedConstant::None => ctx.none(),
             BorrowedConstant::Ellipsis => ctx.ellipsis.clone().into(),
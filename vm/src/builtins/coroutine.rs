@@ -1,4 +1,4 @@
-use super::{PyCode, PyGenericAlias, PyStrRef, PyType, PyTypeRef};
+use super::{PyCode, PyGenericAlias, PyStrRef, PyTupleRef, PyType, PyTypeRef};
 use crate::{
     AsObject, Context, Py, PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine,
     class::PyClassImpl,
@@ -29,9 +29,9 @@ impl PyCoroutine {
         &self.inner
     }
 
-    pub fn new(frame: FrameRef, name: PyStrRef) -> Self {
+    pub fn new(frame: FrameRef, name: PyStrRef, vm: &VirtualMachine) -> Self {
         Self {
-            inner: Coro::new(frame, name),
+            inner: Coro::new(frame, name, vm),
         }
     }
 
@@ -66,11 +66,29 @@ impl PyCoroutine {
     fn cr_code(&self, _vm: &VirtualMachine) -> PyRef<PyCode> {
         self.inner.frame().code.clone()
     }
-    // TODO: coroutine origin tracking:
-    // https://docs.python.org/3/library/sys.html#sys.set_coroutine_origin_tracking_depth
+    /// The stack of `(filename, lineno, name)` frames this coroutine was
+    /// created from, captured when `sys.set_coroutine_origin_tracking_depth`
+    /// is non-zero; `None` when origin tracking is disabled.
     #[pygetset]
-    const fn cr_origin(&self, _vm: &VirtualMachine) -> Option<(PyStrRef, usize, PyStrRef)> {
-        None
+    fn cr_origin(&self, vm: &VirtualMachine) -> Option<PyTupleRef> {
+        let origin = self.inner.origin();
+        if origin.is_empty() {
+            return None;
+        }
+        Some(vm.ctx.new_tuple(
+            origin
+                .iter()
+                .map(|(filename, lineno, name)| {
+                    vm.ctx
+                        .new_tuple(vec![
+                            filename.clone().into(),
+                            vm.ctx.new_int(*lineno).into(),
+                            name.clone().into(),
+                        ])
+                        .into()
+                })
+                .collect(),
+        ))
     }
 
     #[pyclassmethod]
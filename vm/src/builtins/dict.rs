@@ -133,6 +133,12 @@ impl PyDict {
         self.entries.delete(vm, key)
     }
 
+    /// Entry-style upsert: hashes `key` once and either returns the existing
+    /// value or inserts the result of `default` and returns that, without a
+    /// second lookup. This is what `dict.setdefault` is built on, and native
+    /// modules implementing counter/cache idioms (the `d[k] = d.get(k, 0) +
+    /// 1` pattern) should prefer it over a separate `get`/`__setitem__` pair
+    /// to avoid hashing and probing the table twice.
     pub fn get_or_insert(
         &self,
         vm: &VirtualMachine,
@@ -259,8 +265,7 @@ impl PyDict {
         default: OptionalArg<PyObjectRef>,
         vm: &VirtualMachine,
     ) -> PyResult {
-        self.entries
-            .setdefault(vm, &*key, || default.unwrap_or_none(vm))
+        self.get_or_insert(vm, key, || default.unwrap_or_none(vm))
     }
 
     #[pymethod]
@@ -865,7 +870,7 @@ macro_rules! dict_view {
             #[allow(clippy::redundant_closure_call)]
             #[pymethod]
             fn __reduce__(&self, vm: &VirtualMachine) -> PyTupleRef {
-                let iter = builtins_iter(vm).to_owned();
+                let iter = builtins_iter(vm);
                 let internal = self.internal.lock();
                 let entries = match &internal.status {
                     IterStatus::Active(dict) => dict
@@ -937,7 +942,7 @@ macro_rules! dict_view {
             #[allow(clippy::redundant_closure_call)]
             #[pymethod]
             fn __reduce__(&self, vm: &VirtualMachine) -> PyTupleRef {
-                let iter = builtins_reversed(vm).to_owned();
+                let iter = builtins_reversed(vm);
                 let internal = self.internal.lock();
                 // TODO: entries must be reversed too
                 let entries = match &internal.status {
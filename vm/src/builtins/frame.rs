@@ -2,18 +2,21 @@
 
 */
 
-use super::{PyCode, PyDictRef, PyIntRef, PyStrRef};
+use super::{PyCode, PyDictRef, PyIntRef, PyStr, PyStrRef, PyType};
 use crate::{
-    AsObject, Context, Py, PyObjectRef, PyRef, PyResult, VirtualMachine,
+    AsObject, Context, Py, PyObjectRef, PyPayload, PyRef, PyResult, VirtualMachine,
+    atomic_func,
     class::PyClassImpl,
     frame::{Frame, FrameRef},
     function::PySetterValue,
-    types::{Representable, Unconstructible},
+    protocol::PyMappingMethods,
+    types::{AsMapping, Representable, Unconstructible},
 };
 use num_traits::Zero;
 
 pub fn init(context: &Context) {
     Frame::extend_class(context, context.types.frame_type);
+    FrameLocalsProxy::extend_class(context, context.types.frame_locals_proxy_type);
 }
 
 impl Unconstructible for Frame {}
@@ -44,8 +47,8 @@ impl Frame {
     }
 
     #[pygetset]
-    fn f_locals(&self, vm: &VirtualMachine) -> PyResult {
-        self.locals(vm).map(Into::into)
+    fn f_locals(zelf: FrameRef, vm: &VirtualMachine) -> PyRef<FrameLocalsProxy> {
+        FrameLocalsProxy { frame: zelf }.into_ref(&vm.ctx)
     }
 
     #[pygetset]
@@ -124,3 +127,131 @@ impl Py<Frame> {
             .cloned()
     }
 }
+
+/// A write-through view of a frame's fast-locals and cell/free variables
+/// (PEP 667). Unlike the snapshot dict `locals()` returns, reads and writes
+/// through this proxy go straight to the frame's live variable storage, so
+/// mutating it at a breakpoint actually changes what the running code sees.
+#[pyclass(no_attr, module = false, name = "FrameLocalsProxy")]
+#[derive(Debug)]
+pub struct FrameLocalsProxy {
+    frame: FrameRef,
+}
+
+impl PyPayload for FrameLocalsProxy {
+    #[inline]
+    fn class(ctx: &Context) -> &'static Py<PyType> {
+        ctx.types.frame_locals_proxy_type
+    }
+}
+
+impl Unconstructible for FrameLocalsProxy {}
+
+impl Representable for FrameLocalsProxy {
+    #[inline]
+    fn repr_str(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<String> {
+        let items: Vec<String> = zelf
+            .frame
+            .local_names()
+            .into_iter()
+            .filter_map(|name| {
+                let value = zelf.frame.locals_get_direct(name.as_str())?;
+                Some(format!("{}: {}", name, value.repr(vm).ok()?.as_str()))
+            })
+            .collect();
+        Ok(format!("FrameLocalsProxy({{{}}})", items.join(", ")))
+    }
+}
+
+#[pyclass(with(Unconstructible, AsMapping, Representable))]
+impl FrameLocalsProxy {
+    #[pymethod]
+    fn __getitem__(&self, key: PyStrRef, vm: &VirtualMachine) -> PyResult {
+        // Fast-locals storage only covers variables of an "optimized" (function)
+        // frame; module scope, class bodies, and exec()/eval() with an explicit
+        // locals dict keep all of their names in `self.frame.locals` instead.
+        if let Some(value) = self.frame.locals_get_direct(key.as_str()) {
+            return Ok(value);
+        }
+        self.frame.locals.mapping().subscript(&key, vm)
+    }
+
+    #[pymethod]
+    fn __setitem__(&self, key: PyStrRef, value: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+        match self.frame.locals_set_direct(key.as_str(), Some(value)) {
+            Ok(()) => Ok(()),
+            Err(value) => self.frame.locals.mapping().ass_subscript(&key, value, vm),
+        }
+    }
+
+    #[pymethod]
+    fn __delitem__(&self, key: PyStrRef, vm: &VirtualMachine) -> PyResult<()> {
+        if self.frame.locals_get_direct(key.as_str()).is_some() {
+            // a direct local always exists, so clearing it can't fail
+            self.frame
+                .locals_set_direct(key.as_str(), None)
+                .unwrap_or_else(|_| unreachable!());
+            return Ok(());
+        }
+        self.frame.locals.mapping().ass_subscript(&key, None, vm)
+    }
+
+    #[pymethod]
+    fn __len__(&self, vm: &VirtualMachine) -> PyResult<usize> {
+        Ok(self.keys(vm)?.len())
+    }
+
+    #[pymethod]
+    fn keys(&self, vm: &VirtualMachine) -> PyResult<Vec<PyObjectRef>> {
+        let mut seen = std::collections::HashSet::new();
+        let mut keys: Vec<PyObjectRef> = self
+            .frame
+            .local_names()
+            .into_iter()
+            .filter(|name| self.frame.locals_get_direct(name.as_str()).is_some())
+            .map(|name| {
+                seen.insert(name.as_str());
+                name.to_owned().into()
+            })
+            .collect();
+        let mapping_keys = self.frame.locals.mapping().keys(vm)?;
+        for key in vm.extract_elements_with(&mapping_keys, Ok)? {
+            let is_new = match key.downcast_ref::<PyStr>() {
+                Some(s) => !seen.contains(s.as_str()),
+                None => true,
+            };
+            if is_new {
+                keys.push(key);
+            }
+        }
+        Ok(keys)
+    }
+}
+
+impl AsMapping for FrameLocalsProxy {
+    fn as_mapping() -> &'static PyMappingMethods {
+        static AS_MAPPING: PyMappingMethods = PyMappingMethods {
+            length: atomic_func!(
+                |mapping, vm| FrameLocalsProxy::mapping_downcast(mapping).__len__(vm)
+            ),
+            subscript: atomic_func!(|mapping, needle, vm| {
+                let zelf = FrameLocalsProxy::mapping_downcast(mapping);
+                let key: PyStrRef = needle.to_owned().downcast().map_err(|_| {
+                    vm.new_type_error("FrameLocalsProxy keys must be str")
+                })?;
+                zelf.__getitem__(key, vm)
+            }),
+            ass_subscript: atomic_func!(|mapping, needle, value, vm| {
+                let zelf = FrameLocalsProxy::mapping_downcast(mapping);
+                let key: PyStrRef = needle.to_owned().downcast().map_err(|_| {
+                    vm.new_type_error("FrameLocalsProxy keys must be str")
+                })?;
+                match value {
+                    Some(value) => zelf.__setitem__(key, value, vm),
+                    None => zelf.__delitem__(key, vm),
+                }
+            }),
+        };
+        &AS_MAPPING
+    }
+}
@@ -424,9 +424,9 @@ impl Py<PyFunction> {
         let is_gen = code.flags.contains(bytecode::CodeFlags::IS_GENERATOR);
         let is_coro = code.flags.contains(bytecode::CodeFlags::IS_COROUTINE);
         match (is_gen, is_coro) {
-            (true, false) => Ok(PyGenerator::new(frame, self.__name__()).into_pyobject(vm)),
-            (false, true) => Ok(PyCoroutine::new(frame, self.__name__()).into_pyobject(vm)),
-            (true, true) => Ok(PyAsyncGen::new(frame, self.__name__()).into_pyobject(vm)),
+            (true, false) => Ok(PyGenerator::new(frame, self.__name__(), vm).into_pyobject(vm)),
+            (false, true) => Ok(PyCoroutine::new(frame, self.__name__(), vm).into_pyobject(vm)),
+            (true, true) => Ok(PyAsyncGen::new(frame, self.__name__(), vm).into_pyobject(vm)),
             (false, false) => vm.run_frame(frame),
         }
     }
@@ -12,8 +12,8 @@ use crate::{
     types::{IterNext, Iterable, SelfIter},
 };
 use rustpython_common::{
+    context_local,
     lock::{PyMutex, PyRwLock, PyRwLockUpgradableReadGuard},
-    static_cell,
 };
 
 /// Marks status of iterator.
@@ -86,7 +86,7 @@ impl<T> PositionIterInternal<T> {
     where
         F: FnOnce(&T) -> PyObjectRef,
     {
-        let iter = builtins_iter(vm).to_owned();
+        let iter = builtins_iter(vm);
         self._reduce(iter, f, vm)
     }
 
@@ -94,7 +94,7 @@ impl<T> PositionIterInternal<T> {
     where
         F: FnOnce(&T) -> PyObjectRef,
     {
-        let reversed = builtins_reversed(vm).to_owned();
+        let reversed = builtins_reversed(vm);
         self._reduce(reversed, f, vm)
     }
 
@@ -160,18 +160,18 @@ impl<T> PositionIterInternal<T> {
     }
 }
 
-pub fn builtins_iter(vm: &VirtualMachine) -> &PyObject {
-    static_cell! {
-        static INSTANCE: PyObjectRef;
+pub fn builtins_iter(vm: &VirtualMachine) -> PyObjectRef {
+    context_local! {
+        static INSTANCE: Context => PyObjectRef;
     }
-    INSTANCE.get_or_init(|| vm.builtins.get_attr("iter", vm).unwrap())
+    INSTANCE.get_or_init(&vm.ctx, || vm.builtins.get_attr("iter", vm).unwrap())
 }
 
-pub fn builtins_reversed(vm: &VirtualMachine) -> &PyObject {
-    static_cell! {
-        static INSTANCE: PyObjectRef;
+pub fn builtins_reversed(vm: &VirtualMachine) -> PyObjectRef {
+    context_local! {
+        static INSTANCE: Context => PyObjectRef;
     }
-    INSTANCE.get_or_init(|| vm.builtins.get_attr("reversed", vm).unwrap())
+    INSTANCE.get_or_init(&vm.ctx, || vm.builtins.get_attr("reversed", vm).unwrap())
 }
 
 #[pyclass(module = false, name = "iterator", traverse)]
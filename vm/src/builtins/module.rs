@@ -179,9 +179,56 @@ impl PyModule {
         let dict = dict_attr
             .downcast::<PyDict>()
             .map_err(|_| vm.new_type_error("<module>.__dict__ is not a dictionary"))?;
+
+        // PEP 562: a `__dir__` function defined directly in the module's namespace
+        // overrides the default listing of the module's attribute names.
+        if let Some(dir_func) = dict.get_item_opt(identifier!(vm, __dir__), vm)? {
+            let result = dir_func.call((), vm)?;
+            return vm.extract_elements_with(&result, Ok);
+        }
+
         let attrs = dict.into_iter().map(|(k, _v)| k).collect();
         Ok(attrs)
     }
+
+    // Like the type's `__annotations__` getset (see `PyType::__annotations__`),
+    // this lazily creates the dict on first access instead of relying solely
+    // on the compiler's SETUP_ANNOTATIONS, so e.g. `types.ModuleType("m").__annotations__`
+    // works even when the module body has no annotated assignments.
+    #[pygetset]
+    fn __annotations__(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<PyObjectRef> {
+        let __annotations__ = identifier!(vm, __annotations__);
+        let dict = zelf.dict();
+        if let Some(annotations) = dict.get_item_opt(__annotations__, vm)? {
+            return Ok(annotations);
+        }
+        let annotations: PyObjectRef = vm.ctx.new_dict().into();
+        dict.set_item(__annotations__, annotations.clone(), vm)?;
+        Ok(annotations)
+    }
+
+    #[pygetset(setter)]
+    fn set___annotations__(
+        zelf: &Py<Self>,
+        value: Option<PyObjectRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let __annotations__ = identifier!(vm, __annotations__);
+        let dict = zelf.dict();
+        if let Some(value) = value {
+            dict.set_item(__annotations__, value, vm)
+        } else {
+            dict.del_item(__annotations__, vm).map_err(|_| {
+                let module_name = zelf
+                    .name(vm)
+                    .map(|name| format!(" '{name}'"))
+                    .unwrap_or_default();
+                vm.new_attribute_error(format!(
+                    "module{module_name} has no attribute '__annotations__'"
+                ))
+            })
+        }
+    }
 }
 
 impl Initializer for PyModule {
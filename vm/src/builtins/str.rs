@@ -44,6 +44,16 @@ use unic_ucd_category::GeneralCategory;
 use unic_ucd_ident::{is_xid_continue, is_xid_start};
 use unicode_casing::CharExt;
 
+/// The same definition CPython's `Py_UNICODE_ISSPACE` uses: this is what
+/// `str.isspace()` and the no-argument forms of `str.split`/`str.rsplit`
+/// treat as whitespace, locale-independent and covering the full range of
+/// Unicode space separators rather than just ASCII.
+fn is_py_whitespace(c: char) -> bool {
+    use unic_ucd_bidi::bidi_class::abbr_names::*;
+    GeneralCategory::of(c) == GeneralCategory::SpaceSeparator
+        || matches!(BidiClass::of(c), WS | B | S)
+}
+
 impl<'a> TryFromBorrowedObject<'a> for String {
     fn try_from_borrowed_object(vm: &VirtualMachine, obj: &'a PyObject) -> PyResult<Self> {
         obj.try_value_with(|pystr: &PyStr| Ok(pystr.as_str().to_owned()), vm)
@@ -663,8 +673,21 @@ impl PyStr {
 
     // casefold is much more aggressive than lower
     #[pymethod]
-    fn casefold(&self) -> String {
-        caseless::default_case_fold_str(self.as_str())
+    fn casefold(&self) -> Wtf8Buf {
+        match self.as_str_kind() {
+            // Unicode default case folding agrees with simple ASCII lowercasing
+            // over the ASCII range, so skip the full per-char case-fold table
+            // lookup here the same way `lower`/`upper` already do.
+            PyKindStr::Ascii(s) => s.to_ascii_lowercase().into(),
+            PyKindStr::Utf8(s) => caseless::default_case_fold_str(s).into(),
+            PyKindStr::Wtf8(w) => w
+                .chunks()
+                .map(|c| match c {
+                    Wtf8Chunk::Utf8(s) => caseless::default_case_fold_str(s).into(),
+                    Wtf8Chunk::Surrogate(c) => Wtf8Buf::from(c),
+                })
+                .collect::<Wtf8Buf>(),
+        }
     }
 
     #[pymethod]
@@ -993,6 +1016,29 @@ impl PyStr {
     /// uppercase character and the remaining characters are lowercase.
     #[pymethod]
     fn title(&self) -> Wtf8Buf {
+        // Over the ASCII range, titlecase/uppercase/lowercase all agree with
+        // the simple ASCII case conversions (no multi-char expansions), so
+        // an all-ASCII string never needs the Unicode char properties below.
+        if let PyKindStr::Ascii(s) = self.as_str_kind() {
+            let mut title = s.to_owned();
+            let mut previous_is_cased = false;
+            for c in title.as_mut_slice() {
+                if c.is_lowercase() {
+                    if !previous_is_cased {
+                        c.make_ascii_uppercase();
+                    }
+                    previous_is_cased = true;
+                } else if c.is_uppercase() {
+                    if previous_is_cased {
+                        c.make_ascii_lowercase();
+                    }
+                    previous_is_cased = true;
+                } else {
+                    previous_is_cased = false;
+                }
+            }
+            return title.into();
+        }
         let mut title = Wtf8Buf::with_capacity(self.data.len());
         let mut previous_is_cased = false;
         for c_orig in self.as_wtf8().code_points() {
@@ -1088,12 +1134,7 @@ impl PyStr {
 
     #[pymethod]
     fn isspace(&self) -> bool {
-        use unic_ucd_bidi::bidi_class::abbr_names::*;
-        !self.data.is_empty()
-            && self.char_all(|c| {
-                GeneralCategory::of(c) == GeneralCategory::SpaceSeparator
-                    || matches!(BidiClass::of(c), WS | B | S)
-            })
+        !self.data.is_empty() && self.char_all(is_py_whitespace)
     }
 
     // Return true if all cased characters in the string are lowercase and there is at least one cased character, false otherwise.
@@ -2164,11 +2205,12 @@ impl AnyStr for str {
     where
         F: Fn(&Self) -> PyObjectRef,
     {
-        // CPython split_whitespace
+        // CPython split_whitespace -- Unicode-aware, not just ASCII, so this
+        // splits on e.g. U+2003 EM SPACE the same way str.isspace() does.
         let mut splits = Vec::new();
         let mut last_offset = 0;
         let mut count = maxsplit;
-        for (offset, _) in self.match_indices(|c: char| c.is_ascii_whitespace() || c == '\x0b') {
+        for (offset, _) in self.match_indices(is_py_whitespace) {
             if last_offset == offset {
                 last_offset += 1;
                 continue;
@@ -2190,11 +2232,11 @@ impl AnyStr for str {
     where
         F: Fn(&Self) -> PyObjectRef,
     {
-        // CPython rsplit_whitespace
+        // CPython rsplit_whitespace -- Unicode-aware, see py_split_whitespace above.
         let mut splits = Vec::new();
         let mut last_offset = self.len();
         let mut count = maxsplit;
-        for (offset, _) in self.rmatch_indices(|c: char| c.is_ascii_whitespace() || c == '\x0b') {
+        for (offset, _) in self.rmatch_indices(is_py_whitespace) {
             if last_offset == offset + 1 {
                 last_offset -= 1;
                 continue;
@@ -2275,13 +2317,14 @@ impl AnyStr for Wtf8 {
     where
         F: Fn(&Self) -> PyObjectRef,
     {
-        // CPython split_whitespace
+        // CPython split_whitespace -- Unicode-aware, not just ASCII, so this
+        // splits on e.g. U+2003 EM SPACE the same way str.isspace() does.
         let mut splits = Vec::new();
         let mut last_offset = 0;
         let mut count = maxsplit;
         for (offset, _) in self
             .code_point_indices()
-            .filter(|(_, c)| c.is_char_and(|c| c.is_ascii_whitespace() || c == '\x0b'))
+            .filter(|(_, c)| c.is_char_and(is_py_whitespace))
         {
             if last_offset == offset {
                 last_offset += 1;
@@ -2304,14 +2347,14 @@ impl AnyStr for Wtf8 {
     where
         F: Fn(&Self) -> PyObjectRef,
     {
-        // CPython rsplit_whitespace
+        // CPython rsplit_whitespace -- Unicode-aware, see py_split_whitespace above.
         let mut splits = Vec::new();
         let mut last_offset = self.len();
         let mut count = maxsplit;
         for (offset, _) in self
             .code_point_indices()
             .rev()
-            .filter(|(_, c)| c.is_char_and(|c| c.is_ascii_whitespace() || c == '\x0b'))
+            .filter(|(_, c)| c.is_char_and(is_py_whitespace))
         {
             if last_offset == offset + 1 {
                 last_offset -= 1;
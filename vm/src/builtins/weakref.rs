@@ -57,6 +57,11 @@ impl PyWeak {
     fn __class_getitem__(cls: PyTypeRef, args: PyObjectRef, vm: &VirtualMachine) -> PyGenericAlias {
         PyGenericAlias::from_args(cls, args, vm)
     }
+
+    #[pygetset]
+    fn __callback__(&self) -> Option<PyObjectRef> {
+        self.callback()
+    }
 }
 
 impl Hashable for PyWeak {
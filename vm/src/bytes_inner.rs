@@ -235,10 +235,42 @@ pub struct ByteInnerTranslateOptions {
     delete: OptionalArg<PyObjectRef>,
 }
 
+/// A set of bytes to delete during `translate`, stored as a 256-bit
+/// membership table so checking a byte is O(1) instead of a linear scan of
+/// the `delete` argument -- this matters since callers like sanitizers
+/// often pass large delete sets.
+pub struct ByteSet {
+    table: [bool; 256],
+    is_empty: bool,
+}
+
+impl ByteSet {
+    fn new(bytes: &[u8]) -> Self {
+        let mut table = [false; 256];
+        for &b in bytes {
+            table[b as usize] = true;
+        }
+        Self {
+            table,
+            is_empty: bytes.is_empty(),
+        }
+    }
+
+    #[inline]
+    pub fn contains(&self, b: u8) -> bool {
+        self.table[b as usize]
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.is_empty
+    }
+}
+
 impl ByteInnerTranslateOptions {
-    pub fn get_value(self, vm: &VirtualMachine) -> PyResult<(Vec<u8>, Vec<u8>)> {
+    pub fn get_value(self, vm: &VirtualMachine) -> PyResult<(Box<[u8; 256]>, ByteSet)> {
         let table = self.table.map_or_else(
-            || Ok((0..=u8::MAX).collect::<Vec<u8>>()),
+            || Ok(Box::new(std::array::from_fn(|i| i as u8))),
             |v| {
                 let bytes = v
                     .try_into_value::<PyBytesInner>(vm)
@@ -247,16 +279,17 @@ impl ByteInnerTranslateOptions {
                     .ok_or_else(|| {
                         vm.new_value_error("translation table must be 256 characters long")
                     })?;
-                Ok(bytes.elements.to_vec())
+                let table: [u8; 256] = bytes.elements.as_slice().try_into().unwrap();
+                Ok(Box::new(table))
             },
         )?;
 
         let delete = match self.delete {
             OptionalArg::Present(byte) => {
                 let byte: PyBytesInner = byte.try_into_value(vm)?;
-                byte.elements
+                ByteSet::new(&byte.elements)
             }
-            _ => vec![],
+            _ => ByteSet::new(&[]),
         };
 
         Ok((table, delete))
@@ -591,15 +624,17 @@ impl PyBytesInner {
     ) -> PyResult<Vec<u8>> {
         let (table, delete) = options.get_value(vm)?;
 
-        let mut res = if delete.is_empty() {
-            Vec::with_capacity(self.elements.len())
-        } else {
-            Vec::new()
-        };
+        if delete.is_empty() {
+            // No bytes are being dropped, so the output is exactly as long as
+            // the input -- map straight into a single pre-sized allocation
+            // with no per-byte branch.
+            return Ok(self.elements.iter().map(|&b| table[b as usize]).collect());
+        }
 
-        for i in &self.elements {
-            if !delete.contains(i) {
-                res.push(table[*i as usize]);
+        let mut res = Vec::new();
+        for &b in &self.elements {
+            if !delete.contains(b) {
+                res.push(table[b as usize]);
             }
         }
 
@@ -126,9 +126,15 @@ impl TryFromObject for std::time::Duration {
     fn try_from_object(vm: &VirtualMachine, obj: PyObjectRef) -> PyResult<Self> {
         if let Some(float) = obj.downcast_ref::<PyFloat>() {
             let f = float.to_f64();
+            if f.is_nan() {
+                return Err(vm.new_value_error("Invalid value NaN (not a number)"));
+            }
             if f < 0.0 {
                 return Err(vm.new_value_error("negative duration"));
             }
+            if !f.is_finite() || f > Self::MAX.as_secs_f64() {
+                return Err(vm.new_overflow_error("duration value out of range"));
+            }
             Ok(Self::from_secs_f64(f))
         } else if let Some(int) = obj.try_index_opt(vm) {
             let int = int?;
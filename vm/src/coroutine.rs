@@ -24,6 +24,10 @@ impl ExecutionResult {
     }
 }
 
+/// A `(filename, lineno, name)` frame captured for `cr_origin` when
+/// `sys.set_coroutine_origin_tracking_depth` is enabled.
+pub type CoroOriginFrame = (PyStrRef, usize, PyStrRef);
+
 #[derive(Debug)]
 pub struct Coro {
     frame: FrameRef,
@@ -34,6 +38,7 @@ pub struct Coro {
     name: PyMutex<PyStrRef>,
     // qualname
     exception: PyMutex<Option<PyBaseExceptionRef>>, // exc_state
+    origin: Vec<CoroOriginFrame>,
 }
 
 fn gen_name(jen: &PyObject, vm: &VirtualMachine) -> &'static str {
@@ -48,16 +53,39 @@ fn gen_name(jen: &PyObject, vm: &VirtualMachine) -> &'static str {
 }
 
 impl Coro {
-    pub fn new(frame: FrameRef, name: PyStrRef) -> Self {
+    pub fn new(frame: FrameRef, name: PyStrRef, vm: &VirtualMachine) -> Self {
+        let depth = crate::vm::thread::COROUTINE_ORIGIN_TRACKING_DEPTH.get() as usize;
+        let origin = if depth > 0 {
+            vm.frames
+                .borrow()
+                .iter()
+                .rev()
+                .take(depth)
+                .map(|f| (f.code.co_filename(), f.f_lineno(), f.code.co_name()))
+                .collect()
+        } else {
+            Vec::new()
+        };
         Self {
             frame,
             closed: AtomicCell::new(false),
             running: AtomicCell::new(false),
             exception: PyMutex::default(),
             name: PyMutex::new(name),
+            origin,
         }
     }
 
+    pub fn origin(&self) -> &[CoroOriginFrame] {
+        &self.origin
+    }
+
+    /// Whether this generator/coroutine has started but is neither running
+    /// nor finished -- i.e. it is parked at a `yield`/`await` point.
+    pub fn suspended(&self) -> bool {
+        !self.closed.load() && !self.running.load() && self.frame.lasti() > 0
+    }
+
     fn maybe_close(&self, res: &PyResult<ExecutionResult>) {
         match res {
             Ok(ExecutionResult::Return(_)) | Err(_) => self.closed.store(true),
@@ -31,6 +31,27 @@ type IndexIndex = usize;
 /// index into dict.entries
 type EntryIndex = usize;
 
+/// # Concurrency model
+///
+/// All state lives behind a single [`PyRwLock`], so every read (`get`,
+/// `contains`, `next_entry`, ...) takes a read guard for the duration of the
+/// call and every write (`insert`, `delete`, resize/compaction) takes a
+/// write guard. That makes individual operations atomic with respect to
+/// each other -- a reader can never observe a torn entry (a key written
+/// without its value, or a half-moved `entries` vector mid-resize) because
+/// the resize itself happens while holding the write lock.
+///
+/// What is *not* guaranteed is a stable iteration snapshot: iterating is
+/// just repeated calls to [`Dict::next_entry`] with the last returned
+/// `EntryIndex`, each of which reacquires the read lock independently.
+/// Concurrent inserts/deletes from another thread are visible to an
+/// in-progress iteration as soon as they commit, same as CPython's "don't
+/// mutate a dict while iterating it" rule -- callers that need a frozen
+/// view should snapshot with [`Dict::keys`] (or collect `next_entry` output)
+/// up front. [`Dict::has_changed_size`] lets iterators built on top of this
+/// (e.g. the `dict_keyiterator`/`dict_valueiterator` Python objects) detect
+/// a size change and raise `RuntimeError` rather than silently skipping or
+/// repeating entries.
 pub struct Dict<T = PyObjectRef> {
     inner: PyRwLock<DictInner<T>>,
 }
@@ -1135,4 +1156,46 @@ mod tests {
             assert_eq!(hash1, hash2);
         })
     }
+
+    /// Readers doing a live `next_entry` walk must never panic or observe a
+    /// torn entry while another thread is inserting enough keys to force
+    /// repeated resizes, per the locking model documented on [`Dict`].
+    #[cfg(feature = "threading")]
+    #[test]
+    fn test_concurrent_read_during_resize() {
+        use crate::common::rc::PyRc;
+
+        Interpreter::without_stdlib(Default::default()).enter(|vm| {
+            let dict = PyRc::new(Dict::default());
+            for i in 0..4 {
+                dict.insert(vm, &*vm.new_pyobj(i), vm.new_pyobj(i)).unwrap();
+            }
+
+            let readers: Vec<_> = (0..3)
+                .map(|_| {
+                    let dict = PyRc::clone(&dict);
+                    vm.start_thread(move |vm| {
+                        for _ in 0..200 {
+                            let mut position = 0;
+                            while let Some((next, key, _)) = dict.next_entry(position) {
+                                position = next;
+                                // every key `next_entry` hands back must still be
+                                // independently look-up-able -- a torn entry would
+                                // show up here as a panic or a missing key.
+                                assert!(dict.contains(vm, &*key).unwrap());
+                            }
+                        }
+                    })
+                })
+                .collect();
+
+            for i in 4..200 {
+                dict.insert(vm, &*vm.new_pyobj(i), vm.new_pyobj(i)).unwrap();
+            }
+
+            for reader in readers {
+                reader.join().unwrap();
+            }
+        })
+    }
 }
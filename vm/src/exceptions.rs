@@ -11,6 +11,7 @@ use crate::{
     convert::{ToPyException, ToPyObject},
     function::{ArgIterable, FuncArgs, IntoFuncArgs},
     py_io::{self, Write},
+    recursion::ReprGuard,
     stdlib::sys,
     suggestion::offer_suggestions,
     types::{Callable, Constructor, Initializer, Representable},
@@ -705,8 +706,14 @@ impl Initializer for PyBaseException {
 impl Representable for PyBaseException {
     #[inline]
     fn repr_str(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<String> {
-        let repr_args = vm.exception_args_as_string(zelf.args(), false);
         let cls = zelf.class();
+        // args may (directly or indirectly) contain this very exception --
+        // e.g. `e = Exception(); e.args = (e,)` -- so guard against
+        // recursing back into this repr the same way containers do.
+        let Some(_guard) = ReprGuard::enter(vm, zelf.as_object()) else {
+            return Ok(format!("{}(...)", cls.name()));
+        };
+        let repr_args = vm.exception_args_as_string(zelf.args(), false);
         Ok(format!("{}({})", cls.name(), repr_args.iter().format(", ")))
     }
 }
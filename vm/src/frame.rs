@@ -222,6 +222,65 @@ impl Frame {
         }
         Ok(locals.clone())
     }
+
+    /// Look up `name` directly in the fast-locals array or a cell/free
+    /// variable, without going through the `self.locals` mapping. Used by
+    /// [`FrameLocalsProxy`] to give `frame.f_locals` write-through semantics
+    /// (PEP 667) instead of snapshot-dict semantics.
+    pub fn locals_get_direct(&self, name: &str) -> Option<PyObjectRef> {
+        let code = &*self.code;
+        if let Some(idx) = code.varnames.iter().position(|v| v.as_str() == name) {
+            return self.fastlocals.lock()[idx].clone();
+        }
+        if let Some(idx) = code.cellvars.iter().position(|v| v.as_str() == name) {
+            return self.cells_frees[idx].get();
+        }
+        if code.flags.contains(bytecode::CodeFlags::IS_OPTIMIZED)
+            && let Some(idx) = code.freevars.iter().position(|v| v.as_str() == name)
+        {
+            return self.cells_frees[code.cellvars.len() + idx].get();
+        }
+        None
+    }
+
+    /// Write `name` directly into the fast-locals array or a cell/free
+    /// variable. Returns `Err(value)`, handing the value back unconsumed, if
+    /// `name` isn't a local of this frame, in which case the caller should
+    /// fall back to the `self.locals` mapping.
+    pub fn locals_set_direct(
+        &self,
+        name: &str,
+        value: Option<PyObjectRef>,
+    ) -> Result<(), Option<PyObjectRef>> {
+        let code = &*self.code;
+        if let Some(idx) = code.varnames.iter().position(|v| v.as_str() == name) {
+            self.fastlocals.lock()[idx] = value;
+            return Ok(());
+        }
+        if let Some(idx) = code.cellvars.iter().position(|v| v.as_str() == name) {
+            self.cells_frees[idx].set(value);
+            return Ok(());
+        }
+        if code.flags.contains(bytecode::CodeFlags::IS_OPTIMIZED)
+            && let Some(idx) = code.freevars.iter().position(|v| v.as_str() == name)
+        {
+            self.cells_frees[code.cellvars.len() + idx].set(value);
+            return Ok(());
+        }
+        Err(value)
+    }
+
+    /// All local variable names visible to [`Frame::locals_get_direct`], in
+    /// the same order CPython reports them for `f_locals` iteration.
+    pub fn local_names(&self) -> Vec<&PyStrInterned> {
+        let code = &*self.code;
+        let mut names: Vec<&PyStrInterned> =
+            code.varnames.iter().chain(code.cellvars.iter()).copied().collect();
+        if code.flags.contains(bytecode::CodeFlags::IS_OPTIMIZED) {
+            names.extend(code.freevars.iter().copied());
+        }
+        names
+    }
 }
 
 impl Py<Frame> {
@@ -499,6 +558,21 @@ impl ExecutingFrame<'_> {
         vm: &VirtualMachine,
     ) -> FrameResult {
         vm.check_signals()?;
+        vm.check_execution_budget()?;
+        vm.check_memory_limit()?;
+
+        #[cfg(feature = "pystats")]
+        {
+            let name = format!("{instruction:?}");
+            let name = name
+                .split(|c: char| c == ' ' || c == '{' || c == '(')
+                .next()
+                .unwrap_or(&name);
+            crate::stats::record_instruction(name);
+        }
+
+        #[cfg(feature = "gc-stress")]
+        crate::gc::stress_check(vm);
 
         flame_guard!(format!(
             "Frame::execute_instruction({})",
@@ -0,0 +1,166 @@
+//! Reference-cycle bookkeeping and heap consistency verification.
+//!
+//! RustPython objects are reference-counted rather than traced by a
+//! generational collector, so the only thing a "collection" can find is a
+//! cycle of objects that keep each other alive (see [`cyclic_objects`]).
+//! This module also backs an opt-in verification mode (see
+//! [`verify_heap_consistency`]) that walks the same object graph and
+//! checks it against each object's reported [`PyObject::strong_count`],
+//! to catch [`Traverse`] implementations on native payloads that miss a
+//! field -- the kind of bug that otherwise only surfaces later as a
+//! dangling reference or a use-after-free.
+
+use crate::{AsObject, PyObject, PyObjectRef, VirtualMachine, object::Traverse};
+use std::collections::HashMap;
+
+/// Roots for a collection pass: every object directly reachable from a
+/// currently-running frame (its locals, cells/frees, globals and
+/// builtins). Containers unreachable from any live frame are not tracked
+/// by a separate allocation registry the way CPython's GC tracks every
+/// container at construction time, so they are already freed the moment
+/// their last external reference drops -- there is nothing left for a
+/// collection pass to find there. What *is* left to find, and what this
+/// walks for, is reference cycles among objects that are still reachable.
+pub fn live_roots(vm: &VirtualMachine) -> Vec<PyObjectRef> {
+    let mut roots = Vec::new();
+    for frame in vm.frames.borrow().iter() {
+        for name in frame.local_names() {
+            if let Some(value) = frame.locals_get_direct(name.as_str()) {
+                roots.push(value);
+            }
+        }
+        roots.push(frame.globals.clone().into());
+        roots.push(frame.builtins.clone().into());
+    }
+    roots
+}
+
+pub fn direct_referents(obj: &PyObject) -> Vec<PyObjectRef> {
+    let mut children = Vec::new();
+    Traverse::traverse(obj, &mut |child: &PyObject| {
+        children.push(child.to_owned());
+    });
+    children
+}
+
+/// Every object reachable from `roots`, keyed by identity.
+pub fn reachable(roots: Vec<PyObjectRef>) -> HashMap<usize, PyObjectRef> {
+    let mut visited: HashMap<usize, PyObjectRef> = HashMap::new();
+    let mut stack = roots;
+    while let Some(obj) = stack.pop() {
+        let id = obj.get_id();
+        if visited.contains_key(&id) {
+            continue;
+        }
+        visited.insert(id, obj.clone());
+        stack.extend(direct_referents(obj.as_object()));
+    }
+    visited
+}
+
+/// Every object reachable from `roots`, paired with how many of its
+/// incoming edges come from *other visited objects* rather than from a
+/// root slot outside the walked set.
+pub fn reference_census(roots: Vec<PyObjectRef>) -> HashMap<usize, (PyObjectRef, usize)> {
+    let visited = reachable(roots);
+    let mut internal_refs: HashMap<usize, usize> = visited.keys().map(|id| (*id, 0)).collect();
+    for obj in visited.values() {
+        for child_id in direct_referents(obj.as_object()).iter().map(|c| c.get_id()) {
+            if let Some(count) = internal_refs.get_mut(&child_id) {
+                *count += 1;
+            }
+        }
+    }
+    visited
+        .into_iter()
+        .map(|(id, obj)| (id, (obj, internal_refs[&id])))
+        .collect()
+}
+
+/// Find the objects whose *only* references, among everything walked,
+/// originate from other objects in the walked graph -- i.e. the only way
+/// to reach them at all is through a cycle, never directly from a root.
+pub fn cyclic_objects(vm: &VirtualMachine) -> Vec<PyObjectRef> {
+    let census = reference_census(live_roots(vm));
+    census
+        .into_values()
+        .filter(|(obj, internal_refs)| obj.as_object().strong_count() == *internal_refs)
+        .map(|(obj, _)| obj)
+        .collect()
+}
+
+/// A single inconsistency found by [`verify_heap_consistency`].
+#[derive(Debug)]
+pub struct HeapViolation {
+    pub object_id: usize,
+    pub class_name: String,
+    pub detail: String,
+}
+
+impl std::fmt::Display for HeapViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "object at {:#x} (type '{}'): {}",
+            self.object_id, self.class_name, self.detail
+        )
+    }
+}
+
+/// Walk every object reachable from a live frame and cross-check its
+/// [`Traverse`] edges against its reported strong count: an object can
+/// never be traversed *into* (i.e. held as a field) more times than it
+/// has owners, so `internal_refs > strong_count` means some payload's
+/// `Traverse` impl is reporting an edge to an object it doesn't actually
+/// keep alive -- almost always a bug in a hand-written `Traverse` impl
+/// rather than in the generic machinery.
+///
+/// This is the heap-consistency half of the GC stress/verification mode:
+/// the other half, forcing a pass after every allocation, is done by
+/// having [`crate::frame::ExecutingFrame::execute_instruction`] call
+/// [`stress_check`] under the `gc-stress` feature, since RustPython has
+/// no single allocation chokepoint to hook instead.
+pub fn verify_heap_consistency(vm: &VirtualMachine) -> Vec<HeapViolation> {
+    let census = reference_census(live_roots(vm));
+    census
+        .values()
+        .filter_map(|(obj, internal_refs)| {
+            let strong_count = obj.as_object().strong_count();
+            if *internal_refs > strong_count {
+                Some(HeapViolation {
+                    object_id: obj.get_id(),
+                    class_name: obj.class().name().into_owned(),
+                    detail: format!(
+                        "traverse reports {internal_refs} incoming edge(s) from the live heap \
+                         but strong_count is only {strong_count}"
+                    ),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "gc-stress")]
+fn stress_enabled() -> bool {
+    use std::sync::OnceLock;
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| std::env::var_os("RUSTPYTHON_GC_STRESS").is_some())
+}
+
+/// Called after every instruction when built with the `gc-stress` feature.
+/// Re-walks the whole live heap on every step (hence "stress": this is
+/// far too slow for normal use) and panics with the first violation found,
+/// so a CI run with `--features gc-stress` turns a rare traverse-impl bug
+/// into an immediate, reproducible crash instead of a dangling reference
+/// discovered much later.
+#[cfg(feature = "gc-stress")]
+pub fn stress_check(vm: &VirtualMachine) {
+    if !stress_enabled() {
+        return;
+    }
+    if let Some(violation) = verify_heap_consistency(vm).into_iter().next() {
+        panic!("gc-stress: heap consistency check failed: {violation}");
+    }
+}
@@ -7,6 +7,93 @@ use crate::{
     version::get_git_revision,
     vm::{VirtualMachine, thread},
 };
+#[cfg(feature = "rustpython-compiler")]
+use crate::{builtins::PyModule, function::OptionalArg};
+
+/// A source of Rust-backed modules for import hooks registered via
+/// [`VirtualMachine::register_module_provider`]. Implementations return
+/// Python source text for any module name they recognize (including dotted
+/// submodules under their prefix); RustPython compiles and executes it
+/// exactly as it would a file loaded from disk. There's no way to hand back
+/// a pre-built native module here -- for that, register the module directly
+/// with [`VirtualMachine::add_native_module`] instead.
+pub trait ModuleProvider: Send + Sync {
+    fn get_source(&self, module_name: &str) -> Option<String>;
+}
+
+pub(crate) fn find_provided_source(vm: &VirtualMachine, module_name: &str) -> Option<String> {
+    vm.state
+        .module_providers
+        .lock()
+        .iter()
+        .find(|(prefix, _)| {
+            module_name == prefix.as_str()
+                || module_name
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.starts_with('.'))
+        })
+        .and_then(|(_, provider)| provider.get_source(module_name))
+}
+
+/// The `sys.meta_path` finder (and loader) backing
+/// [`VirtualMachine::register_module_provider`]. One instance is installed by
+/// [`init_importlib_package`] ahead of `PathFinder`, so a registered prefix
+/// shadows same-named modules that would otherwise be found on `sys.path`.
+#[cfg(feature = "rustpython-compiler")]
+#[pyclass(module = false, name = "rust_module_finder")]
+#[derive(Debug, PyPayload)]
+pub(crate) struct RustModuleFinder;
+
+#[cfg(feature = "rustpython-compiler")]
+#[pyclass]
+impl RustModuleFinder {
+    #[pymethod]
+    fn find_spec(
+        zelf: PyRef<Self>,
+        name: crate::builtins::PyStrRef,
+        _path: OptionalArg<PyObjectRef>,
+        _target: OptionalArg<PyObjectRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult {
+        if find_provided_source(vm, name.as_str()).is_none() {
+            return Ok(vm.ctx.none());
+        }
+        let module_spec = vm
+            .import("_frozen_importlib", 0)?
+            .get_attr("ModuleSpec", vm)?;
+        let loader: PyObjectRef = zelf.into();
+        module_spec.call((name, loader), vm)
+    }
+
+    #[pymethod]
+    fn create_module(&self, _spec: PyObjectRef) -> Option<PyObjectRef> {
+        // None tells importlib to fall back to a plain module object.
+        None
+    }
+
+    #[pymethod]
+    fn exec_module(&self, module: PyRef<PyModule>, vm: &VirtualMachine) -> PyResult<()> {
+        let name = module.as_object().get_attr(identifier!(vm, __name__), vm)?;
+        let name: crate::builtins::PyStrRef = name.try_into_value(vm)?;
+        let source = find_provided_source(vm, name.as_str()).ok_or_else(|| {
+            vm.new_import_error(
+                format!("no source provided for module {}", name.as_str()),
+                name.clone(),
+            )
+        })?;
+        let code = vm
+            .compile_with_opts(
+                &source,
+                crate::compiler::Mode::Exec,
+                format!("<rust-module:{}>", name.as_str()),
+                vm.compile_opts(),
+            )
+            .map_err(|err| vm.new_syntax_error(&err, Some(&source)))?;
+        let scope = Scope::with_builtins(None, module.dict(), vm);
+        vm.run_code_obj(code, scope)?;
+        Ok(())
+    }
+}
 
 pub(crate) fn init_importlib_base(vm: &mut VirtualMachine) -> PyResult<PyObjectRef> {
     flame_guard!("init importlib");
@@ -64,6 +151,17 @@ pub(crate) fn init_importlib_package(vm: &VirtualMachine, importlib: PyObjectRef
         if zipimport_res.is_err() {
             warn!("couldn't init zipimport")
         }
+
+        // Give embedder-registered module providers first crack at every
+        // import, ahead of PathFinder, so a registered prefix shadows
+        // same-named modules that would otherwise be found on sys.path.
+        #[cfg(feature = "rustpython-compiler")]
+        {
+            let meta_path = vm.sys_module.get_attr("meta_path", vm)?;
+            let meta_path = list::PyListRef::try_from_object(vm, meta_path)?;
+            meta_path.insert(0, RustModuleFinder.into_ref(&vm.ctx).into());
+        }
+
         Ok(())
     })
 }
@@ -100,6 +198,12 @@ pub fn import_builtin(vm: &VirtualMachine, module_name: &str) -> PyResult {
     Ok(module.into())
 }
 
+/// Compiles and executes a single module's source. `rustpython_compiler::compile_with_opts`
+/// itself is a pure function of its arguments, so in principle the compile step for the
+/// independent modules of an import cascade could run ahead of execution on a thread pool --
+/// but `vm.compile_opts()` and the syntax error path here both borrow this module's single
+/// `&VirtualMachine`, which isn't `Sync`, so there's no way to fan that out across threads
+/// without first giving compilation its own interpreter-independent context.
 #[cfg(feature = "rustpython-compiler")]
 pub fn import_file(
     vm: &VirtualMachine,
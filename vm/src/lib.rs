@@ -59,6 +59,7 @@ pub mod exceptions;
 pub mod format;
 pub mod frame;
 pub mod function;
+pub mod gc;
 pub mod import;
 mod intern;
 pub mod iter;
@@ -69,6 +70,7 @@ pub mod ospath;
 
 pub mod prelude;
 pub mod protocol;
+pub mod py_future;
 pub mod py_io;
 
 #[cfg(feature = "serde")]
@@ -80,11 +82,16 @@ pub mod scope;
 pub mod sequence;
 pub mod signal;
 pub mod sliceable;
+
+#[cfg(feature = "pystats")]
+pub mod stats;
+
 pub mod stdlib;
 pub mod suggestion;
 pub mod types;
 pub mod utils;
 pub mod version;
+pub mod vfs;
 pub mod vm;
 pub mod warn;
 
@@ -96,7 +103,7 @@ pub use self::object::{
     AsObject, Py, PyAtomicRef, PyExact, PyObject, PyObjectRef, PyPayload, PyRef, PyRefExact,
     PyResult, PyWeakRef,
 };
-pub use self::vm::{Context, Interpreter, Settings, VirtualMachine};
+pub use self::vm::{Context, ExecutionBudget, Interpreter, Settings, VirtualMachine};
 
 pub use rustpython_common as common;
 pub use rustpython_compiler_core::{bytecode, frozen};
@@ -37,6 +37,7 @@ use std::{
     mem::ManuallyDrop,
     ops::Deref,
     ptr::{self, NonNull},
+    sync::atomic::{AtomicUsize, Ordering},
 };
 
 // so, PyObjectRef is basically equivalent to `PyRc<PyInner<dyn PyObjectPayload>>`, except it's
@@ -76,7 +77,36 @@ use std::{
 #[derive(Debug)]
 pub(super) struct Erased;
 
+/// Running total of bytes handed out by [`PyInner::new`] and not yet given
+/// back by [`drop_dealloc_obj`]. As the module doc above notes, objects are
+/// interpreter-independent -- there's no record of *which* `VirtualMachine`
+/// asked for an allocation, only that one was made -- so this is a single
+/// process-wide counter rather than one per `VirtualMachine`. It only
+/// accounts for the fixed-size `PyInner<T>` allocation itself, not any
+/// further heap storage a payload owns (e.g. a `Vec`'s backing buffer).
+static BYTES_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+/// Running total of live [`PyInner`] allocations, i.e. how many `PyObject`s
+/// currently exist. Same process-wide scope as [`BYTES_ALLOCATED`] -- it
+/// isn't tagged by `VirtualMachine` either.
+static ALLOCATED_BLOCKS: AtomicUsize = AtomicUsize::new(0);
+
+/// The current value of [`BYTES_ALLOCATED`]. Exposed as
+/// [`crate::vm::VirtualMachine::memory_usage`] for embedders.
+pub fn bytes_allocated() -> usize {
+    BYTES_ALLOCATED.load(Ordering::Relaxed)
+}
+
+/// The current value of [`ALLOCATED_BLOCKS`]. Exposed as `sys.getallocatedblocks`
+/// for leak hunting -- a count that keeps climbing across GC runs points at a
+/// reference cycle or a native-side reference leak.
+pub fn allocated_blocks() -> usize {
+    ALLOCATED_BLOCKS.load(Ordering::Relaxed)
+}
+
 pub(super) unsafe fn drop_dealloc_obj<T: PyObjectPayload>(x: *mut PyObject) {
+    BYTES_ALLOCATED.fetch_sub(size_of::<PyInner<T>>(), Ordering::Relaxed);
+    ALLOCATED_BLOCKS.fetch_sub(1, Ordering::Relaxed);
     drop(unsafe { Box::from_raw(x as *mut PyInner<T>) });
 }
 pub(super) unsafe fn debug_obj<T: PyObjectPayload>(
@@ -364,6 +394,17 @@ impl PyWeak {
         guard.obj.is_none()
     }
 
+    /// The callback passed to `weakref.ref(obj, callback)`, if any and if
+    /// the referent is still alive (matches CPython's `ref.__callback__`,
+    /// which also reads back `None` once the referent -- and therefore the
+    /// chance to ever invoke the callback -- is gone).
+    pub(crate) fn callback(&self) -> Option<PyObjectRef> {
+        let guard = unsafe { self.parent.as_ref().lock() };
+        guard.obj?;
+        // SAFETY: holding the parent lock, as required to access `callback`.
+        unsafe { &*self.callback.get() }.clone()
+    }
+
     fn drop_inner(&self) {
         let dealloc = {
             let mut guard = unsafe { self.parent.as_ref().lock() };
@@ -444,6 +485,8 @@ impl InstanceDict {
 impl<T: PyObjectPayload> PyInner<T> {
     fn new(payload: T, typ: PyTypeRef, dict: Option<PyDictRef>) -> Box<Self> {
         let member_count = typ.slots.member_count;
+        BYTES_ALLOCATED.fetch_add(size_of::<Self>(), Ordering::Relaxed);
+        ALLOCATED_BLOCKS.fetch_add(1, Ordering::Relaxed);
         Box::new(Self {
             ref_count: RefCount::new(),
             typeid: T::payload_type_id(),
@@ -845,10 +888,26 @@ impl PyObject {
     /// # Safety
     /// This call will make the object live forever.
     pub(crate) unsafe fn mark_intern(&self) {
-        self.0.ref_count.leak();
+        unsafe { self.mark_immortal() }
     }
 
     pub(crate) fn is_interned(&self) -> bool {
+        self.is_immortal()
+    }
+
+    /// Mark this object as immortal ([PEP 683](https://peps.python.org/pep-0683/)):
+    /// its refcount will never reach zero, so `inc`/`dec` skip the atomic
+    /// update entirely rather than bouncing a shared cache line between
+    /// every thread that touches `None`, `True`, a small int, or an
+    /// interned string.
+    ///
+    /// # Safety
+    /// This call will make the object live forever.
+    pub(crate) unsafe fn mark_immortal(&self) {
+        self.0.ref_count.leak();
+    }
+
+    pub(crate) fn is_immortal(&self) -> bool {
         self.0.ref_count.is_leaked()
     }
 
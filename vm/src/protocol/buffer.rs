@@ -52,6 +52,12 @@ impl PyBuffer {
         zelf
     }
 
+    /// Borrow the underlying bytes as `&[u8]` behind a guard, the other
+    /// direction of the bridge from [`Self::from_byte_vector`]/
+    /// [`Self::from_byte_slice`] -- returns `None` if the buffer isn't
+    /// contiguous (e.g. a strided `memoryview` slice), in which case
+    /// [`Self::append_to`]/[`Self::contiguous_or_collect`] can still collect
+    /// it into an owned `Vec<u8>`.
     pub fn as_contiguous(&self) -> Option<BorrowedValue<'_, [u8]>> {
         self.desc
             .is_contiguous()
@@ -72,6 +78,15 @@ impl PyBuffer {
         )
     }
 
+    /// Wrap a borrowed byte slice as a Python object exporting the buffer
+    /// protocol. Unlike [`Self::from_byte_vector`], which moves an owned
+    /// `Vec<u8>` in with no copy, this makes one copy up front since the
+    /// returned object's lifetime can't be tied to the slice's -- pass an
+    /// owned `Vec<u8>` to `from_byte_vector` instead if you already have one.
+    pub fn from_byte_slice(bytes: &[u8], vm: &VirtualMachine) -> Self {
+        Self::from_byte_vector(bytes.to_vec(), vm)
+    }
+
     /// # Safety
     /// assume the buffer is contiguous
     pub unsafe fn contiguous_unchecked(&self) -> BorrowedValue<'_, [u8]> {
@@ -237,6 +252,23 @@ impl BufferDescriptor {
         true
     }
 
+    /// Same check as [`Self::is_contiguous`], but for Fortran (column-major)
+    /// order: the first dimension is the one with itemsize stride rather
+    /// than the last. For `ndim <= 1` this is equivalent to C-contiguity.
+    pub fn is_fortran_contiguous(&self) -> bool {
+        if self.len == 0 {
+            return true;
+        }
+        let mut sd = self.itemsize;
+        for (shape, stride, _) in self.dim_desc.iter().cloned() {
+            if shape > 1 && stride != sd as isize {
+                return false;
+            }
+            sd *= shape;
+        }
+        true
+    }
+
     /// this function do not check the bound
     /// panic if indices.len() != ndim
     pub fn fast_position(&self, indices: &[usize]) -> isize {
@@ -0,0 +1,130 @@
+//! Async interop for embedders: bridge a Rust [`Future`] into an object
+//! Python code can `await`, and drive a Python coroutine as a Rust `Future`
+//! so it can be polled from a host executor.
+//!
+//! Neither direction wires up a real reactor -- `rustpython-vm` doesn't
+//! depend on tokio or futures-executor, so nothing here can be woken by an
+//! actual IO event. [`PyFuture`] just asks to be polled again on every
+//! `__next__`, and [`CoroutineFuture::poll`] does the same via its
+//! waker; that's enough for an embedder's own single-threaded event loop
+//! (e.g. a tokio `LocalSet` spin-polling between other work) to share a
+//! Python coroutine and a Rust future on the same turn, but it isn't a
+//! substitute for a real executor.
+
+use crate::{
+    Py, PyObjectRef, PyResult, VirtualMachine,
+    common::lock::PyMutex,
+    protocol::{PyIter, PyIterReturn},
+    types::{IterNext, Iterable, SelfIter, Unconstructible},
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "threading")] {
+        /// A boxed Rust future producing a [`PyResult`]. Under the
+        /// `threading` feature `PyObjectRef` is `Send + Sync`, so the
+        /// future itself is required to be too.
+        pub type BoxFuture = Pin<Box<dyn Future<Output = PyResult> + Send + Sync>>;
+    } else {
+        /// A boxed Rust future producing a [`PyResult`].
+        pub type BoxFuture = Pin<Box<dyn Future<Output = PyResult>>>;
+    }
+}
+
+/// Wraps a Rust [`Future`] as a Python object exporting the awaitable
+/// protocol (`__await__` returns `self`, and each `__next__` polls the
+/// future once; there is no `send` method). Not constructible from Python -- build one with
+/// [`PyFuture::new`] and hand it to Python code as a return value or
+/// argument.
+#[pyclass(module = false, name = "rust_future")]
+#[derive(PyPayload)]
+pub struct PyFuture {
+    inner: PyMutex<Option<BoxFuture>>,
+}
+
+impl std::fmt::Debug for PyFuture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PyFuture").finish_non_exhaustive()
+    }
+}
+
+impl PyFuture {
+    pub fn new(future: BoxFuture) -> Self {
+        Self {
+            inner: PyMutex::new(Some(future)),
+        }
+    }
+}
+
+#[pyclass(with(Unconstructible, Iterable, IterNext))]
+impl PyFuture {
+    #[pymethod]
+    const fn __await__(zelf: crate::PyRef<Self>) -> crate::PyRef<Self> {
+        zelf
+    }
+}
+
+impl Unconstructible for PyFuture {}
+impl SelfIter for PyFuture {}
+
+impl IterNext for PyFuture {
+    fn next(zelf: &Py<Self>, vm: &VirtualMachine) -> PyResult<PyIterReturn> {
+        let mut guard = zelf.inner.lock();
+        let future = guard
+            .as_mut()
+            .ok_or_else(|| vm.new_runtime_error("cannot reuse already awaited future"))?;
+        // No reactor is wired up, so there's nothing for the waker to
+        // meaningfully notify -- a no-op waker plus "yield and ask to be
+        // polled again" is the most we can promise.
+        let mut cx = Context::from_waker(Waker::noop());
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(result) => {
+                *guard = None;
+                Ok(PyIterReturn::StopIteration(Some(result?)))
+            }
+            Poll::Pending => Ok(PyIterReturn::Return(vm.ctx.none())),
+        }
+    }
+}
+
+/// Drives a Python coroutine (or any object implementing the iterator
+/// protocol via `__await__`/`send`, e.g. [`PyFuture`] itself) as a Rust
+/// [`Future`], the other half of the bridge from [`PyFuture`]. Borrows the
+/// [`VirtualMachine`] for as long as it's polled, so it's meant for a
+/// single-threaded embedding where the Rust executor and the interpreter
+/// share a thread -- it can't be `tokio::spawn`ed onto a multi-threaded
+/// runtime.
+pub struct CoroutineFuture<'vm> {
+    coro: PyObjectRef,
+    vm: &'vm VirtualMachine,
+}
+
+impl<'vm> CoroutineFuture<'vm> {
+    pub fn new(coro: PyObjectRef, vm: &'vm VirtualMachine) -> Self {
+        Self { coro, vm }
+    }
+}
+
+impl Future for CoroutineFuture<'_> {
+    type Output = PyResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        match PyIter::new(&*this.coro).next(this.vm) {
+            Ok(PyIterReturn::Return(_yielded)) => {
+                // Ask to be polled again immediately -- see the module doc
+                // on why there's no real wakeup source to wait for instead.
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            Ok(PyIterReturn::StopIteration(value)) => {
+                Poll::Ready(Ok(value.unwrap_or_else(|| this.vm.ctx.none())))
+            }
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
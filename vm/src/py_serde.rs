@@ -1,10 +1,14 @@
+use malachite_bigint::BigInt;
 use num_traits::cast::ToPrimitive;
 use num_traits::sign::Signed;
 use serde::de::{DeserializeSeed, Visitor};
-use serde::ser::{Serialize, SerializeMap, SerializeSeq};
+use serde::ser::{
+    Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant,
+};
 
 use crate::builtins::{PyStr, bool_, dict::PyDictRef, float, int, list::PyList, tuple::PyTuple};
-use crate::{AsObject, PyObject, PyObjectRef, VirtualMachine};
+use crate::{AsObject, PyObject, PyObjectRef, PyResult, VirtualMachine};
 
 #[inline]
 pub fn serialize<S>(
@@ -210,3 +214,503 @@ impl<'de> Visitor<'de> for PyObjectDeserializer<'de> {
         Ok(dict.into())
     }
 }
+
+/// An owned error used by [`to_pyobject`]/[`from_pyobject`] -- unlike
+/// [`serialize`]/[`deserialize`] above, these don't have a caller-supplied
+/// format with its own error type, so we need one of our own to satisfy
+/// `serde::ser::Error`/`serde::de::Error`.
+#[derive(Debug)]
+pub struct Error(String);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::ser::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// Convert any [`serde::Serialize`] Rust value into a [`PyObjectRef`], e.g.
+/// to hand a native module's config struct to Python as a dict. This is
+/// `vm.from_serde` -- the counterpart of [`from_pyobject`], which goes the
+/// other way.
+pub fn to_pyobject<T>(vm: &VirtualMachine, value: &T) -> PyResult<PyObjectRef>
+where
+    T: Serialize + ?Sized,
+{
+    value
+        .serialize(ToPyObjectSerializer { vm })
+        .map_err(|e| vm.new_value_error(e.0))
+}
+
+/// Convert a [`PyObject`] into any [`serde::de::DeserializeOwned`] Rust
+/// value, e.g. to pull a dict the embedded script returned into a native
+/// config struct (including a `#[derive(Deserialize)]` dataclass-shaped
+/// struct). This is `vm.to_serde` -- the counterpart of [`to_pyobject`].
+pub fn from_pyobject<T>(vm: &VirtualMachine, pyobject: &PyObject) -> PyResult<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    T::deserialize(FromPyObjectDeserializer {
+        pyobject: pyobject.to_owned(),
+        vm,
+    })
+    .map_err(|e| vm.new_value_error(e.0))
+}
+
+struct ToPyObjectSerializer<'a> {
+    vm: &'a VirtualMachine,
+}
+
+struct PySeqBuilder<'a> {
+    vm: &'a VirtualMachine,
+    elements: Vec<PyObjectRef>,
+}
+
+impl SerializeSeq for PySeqBuilder<'_> {
+    type Ok = PyObjectRef;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.elements.push(to_pyobject(self.vm, value)?.into());
+        Ok(())
+    }
+
+    fn end(self) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.new_list(self.elements).into())
+    }
+}
+
+impl SerializeTuple for PySeqBuilder<'_> {
+    type Ok = PyObjectRef;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.new_tuple(self.elements).into())
+    }
+}
+
+impl SerializeTupleStruct for PySeqBuilder<'_> {
+    type Ok = PyObjectRef;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.new_tuple(self.elements).into())
+    }
+}
+
+impl SerializeTupleVariant for PySeqBuilder<'_> {
+    type Ok = PyObjectRef;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.new_tuple(self.elements).into())
+    }
+}
+
+struct PyMapBuilder<'a> {
+    vm: &'a VirtualMachine,
+    dict: PyDictRef,
+    pending_key: Option<PyObjectRef>,
+}
+
+impl PyMapBuilder<'_> {
+    fn insert(&self, key: PyObjectRef, value: PyObjectRef) -> Result<(), Error> {
+        self.dict
+            .set_item(&*key, value, self.vm)
+            .map_err(|e| Error(e.to_string()))
+    }
+}
+
+impl SerializeMap for PyMapBuilder<'_> {
+    type Ok = PyObjectRef;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.pending_key = Some(to_pyobject(self.vm, key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .pending_key
+            .take()
+            .expect("serialize_value called before serialize_key");
+        self.insert(key, to_pyobject(self.vm, value)?)
+    }
+
+    fn end(self) -> Result<PyObjectRef, Error> {
+        Ok(self.dict.into())
+    }
+}
+
+impl SerializeStruct for PyMapBuilder<'_> {
+    type Ok = PyObjectRef;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.insert(
+            self.vm.ctx.new_str(key).into(),
+            to_pyobject(self.vm, value)?,
+        )
+    }
+
+    fn end(self) -> Result<PyObjectRef, Error> {
+        Ok(self.dict.into())
+    }
+}
+
+impl SerializeStructVariant for PyMapBuilder<'_> {
+    type Ok = PyObjectRef;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<PyObjectRef, Error> {
+        SerializeStruct::end(self)
+    }
+}
+
+impl<'a> serde::Serializer for ToPyObjectSerializer<'a> {
+    type Ok = PyObjectRef;
+    type Error = Error;
+    type SerializeSeq = PySeqBuilder<'a>;
+    type SerializeTuple = PySeqBuilder<'a>;
+    type SerializeTupleStruct = PySeqBuilder<'a>;
+    type SerializeTupleVariant = PySeqBuilder<'a>;
+    type SerializeMap = PyMapBuilder<'a>;
+    type SerializeStruct = PyMapBuilder<'a>;
+    type SerializeStructVariant = PyMapBuilder<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.new_bool(v).into())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<PyObjectRef, Error> {
+        self.serialize_i64(v.into())
+    }
+    fn serialize_i16(self, v: i16) -> Result<PyObjectRef, Error> {
+        self.serialize_i64(v.into())
+    }
+    fn serialize_i32(self, v: i32) -> Result<PyObjectRef, Error> {
+        self.serialize_i64(v.into())
+    }
+    fn serialize_i64(self, v: i64) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.new_int(v).into())
+    }
+    fn serialize_i128(self, v: i128) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.new_int(BigInt::from(v)).into())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<PyObjectRef, Error> {
+        self.serialize_u64(v.into())
+    }
+    fn serialize_u16(self, v: u16) -> Result<PyObjectRef, Error> {
+        self.serialize_u64(v.into())
+    }
+    fn serialize_u32(self, v: u32) -> Result<PyObjectRef, Error> {
+        self.serialize_u64(v.into())
+    }
+    fn serialize_u64(self, v: u64) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.new_int(v).into())
+    }
+    fn serialize_u128(self, v: u128) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.new_int(BigInt::from(v)).into())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<PyObjectRef, Error> {
+        self.serialize_f64(v.into())
+    }
+    fn serialize_f64(self, v: f64) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.new_float(v).into())
+    }
+
+    fn serialize_char(self, v: char) -> Result<PyObjectRef, Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+    fn serialize_str(self, v: &str) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.new_str(v).into())
+    }
+    fn serialize_bytes(self, v: &[u8]) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.new_bytes(v.to_vec()).into())
+    }
+
+    fn serialize_none(self) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.none())
+    }
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<PyObjectRef, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<PyObjectRef, Error> {
+        Ok(self.vm.ctx.none())
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<PyObjectRef, Error> {
+        self.serialize_unit()
+    }
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<PyObjectRef, Error> {
+        self.serialize_str(variant)
+    }
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<PyObjectRef, Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<PyObjectRef, Error> {
+        let dict = self.vm.ctx.new_dict();
+        dict.set_item(variant, to_pyobject(self.vm, value)?, self.vm)
+            .map_err(|e| Error(e.to_string()))?;
+        Ok(dict.into())
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<PySeqBuilder<'a>, Error> {
+        Ok(PySeqBuilder {
+            vm: self.vm,
+            elements: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+    fn serialize_tuple(self, len: usize) -> Result<PySeqBuilder<'a>, Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<PySeqBuilder<'a>, Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        len: usize,
+    ) -> Result<PySeqBuilder<'a>, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<PyMapBuilder<'a>, Error> {
+        Ok(PyMapBuilder {
+            vm: self.vm,
+            dict: self.vm.ctx.new_dict(),
+            pending_key: None,
+        })
+    }
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<PyMapBuilder<'a>, Error> {
+        self.serialize_map(Some(len))
+    }
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        len: usize,
+    ) -> Result<PyMapBuilder<'a>, Error> {
+        self.serialize_map(Some(len))
+    }
+}
+
+/// Deserializes a Rust value straight out of an existing [`PyObject`], so
+/// e.g. a `Vec<i32>` can be read back from a Python list without going
+/// through an intermediate textual format. A [`PyObject`] is self-describing
+/// the same way `serde_json::Value` is, so like most such adapters this just
+/// routes every `deserialize_*` call through `deserialize_any`.
+struct FromPyObjectDeserializer<'a> {
+    // Owned (rather than `&'a PyObject`) so we can freely build one of these
+    // around a freshly-produced list/dict element, whose lifetime is tied to
+    // a local iterator rather than to the original `from_pyobject` caller.
+    pyobject: PyObjectRef,
+    vm: &'a VirtualMachine,
+}
+
+impl<'a> FromPyObjectDeserializer<'a> {
+    fn with(&self, pyobject: PyObjectRef) -> Self {
+        Self {
+            pyobject,
+            vm: self.vm,
+        }
+    }
+}
+
+impl<'de> serde::Deserializer<'de> for FromPyObjectDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let vm = self.vm;
+        let obj = &self.pyobject;
+        if vm.is_none(obj) {
+            visitor.visit_unit()
+        } else if let Some(s) = obj.downcast_ref::<PyStr>() {
+            visitor.visit_str(s.as_ref())
+        } else if obj.fast_isinstance(vm.ctx.types.bool_type) {
+            visitor.visit_bool(bool_::get_value(obj))
+        } else if obj.fast_isinstance(vm.ctx.types.int_type) {
+            let v = int::get_value(obj);
+            if v.is_negative() {
+                visitor.visit_i64(
+                    v.to_i64()
+                        .ok_or_else(|| Error("int too large".to_owned()))?,
+                )
+            } else {
+                visitor.visit_u64(
+                    v.to_u64()
+                        .ok_or_else(|| Error("int too large".to_owned()))?,
+                )
+            }
+        } else if obj.fast_isinstance(vm.ctx.types.float_type) {
+            visitor.visit_f64(float::get_value(obj))
+        } else if let Some(list) = obj.downcast_ref::<PyList>() {
+            visitor.visit_seq(PySeqAccess {
+                de: &self,
+                iter: list.borrow_vec().to_vec().into_iter(),
+            })
+        } else if let Some(tuple) = obj.downcast_ref::<PyTuple>() {
+            visitor.visit_seq(PySeqAccess {
+                de: &self,
+                iter: tuple.to_vec().into_iter(),
+            })
+        } else if obj.fast_isinstance(vm.ctx.types.dict_type) {
+            let dict: PyDictRef = obj.to_owned().downcast().unwrap();
+            visitor.visit_map(PyMapAccess {
+                de: &self,
+                iter: dict.into_iter(),
+                pending_value: None,
+            })
+        } else {
+            Err(Error(format!(
+                "Object of type '{}' is not deserializable",
+                obj.class()
+            )))
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.vm.is_none(&self.pyobject) {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        // A single-entry {"Variant": value} dict (newtype/struct variants) or
+        // a bare string (unit variants) -- matches what `to_pyobject` writes.
+        if let Some(s) = self.pyobject.downcast_ref::<PyStr>() {
+            visitor.visit_enum(serde::de::value::StrDeserializer::new(s.as_ref()))
+        } else {
+            self.deserialize_any(visitor)
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple tuple_struct
+        map struct identifier ignored_any
+    }
+}
+
+struct PySeqAccess<'a, 'b> {
+    de: &'b FromPyObjectDeserializer<'a>,
+    iter: std::vec::IntoIter<PyObjectRef>,
+}
+
+impl<'de> serde::de::SeqAccess<'de> for PySeqAccess<'_, '_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.iter.next() {
+            Some(item) => seed.deserialize(self.de.with(item)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+struct PyMapAccess<'a, 'b> {
+    de: &'b FromPyObjectDeserializer<'a>,
+    iter: crate::builtins::dict::DictIntoIter,
+    pending_value: Option<PyObjectRef>,
+}
+
+impl<'de> serde::de::MapAccess<'de> for PyMapAccess<'_, '_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.pending_value = Some(value);
+                seed.deserialize(self.de.with(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let value = self
+            .pending_value
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+        seed.deserialize(self.de.with(value))
+    }
+}
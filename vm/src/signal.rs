@@ -10,6 +10,14 @@ use std::{
 
 pub(crate) const NSIG: usize = 64;
 static ANY_TRIGGERED: AtomicBool = AtomicBool::new(false);
+/// Set (async-signal-safely, from the OS signal handler) when a write to the
+/// wakeup fd installed by `signal.set_wakeup_fd(fd, warn_on_full_buffer=True)`
+/// couldn't complete because its buffer was full, meaning a signal may have
+/// been missed by whatever loop (e.g. asyncio) is draining that fd. Actually
+/// raising the warning has to wait until [`check_signals`] runs on the main
+/// thread, since emitting a Python-level `ResourceWarning` isn't something a
+/// signal handler can safely do.
+pub(crate) static WAKEUP_FD_OVERFLOWED: AtomicBool = AtomicBool::new(false);
 // hack to get around const array repeat expressions, rust issue #79270
 #[allow(clippy::declare_interior_mutable_const)]
 const ATOMIC_FALSE: AtomicBool = AtomicBool::new(false);
@@ -22,6 +30,15 @@ pub fn check_signals(vm: &VirtualMachine) -> PyResult<()> {
         return Ok(());
     }
 
+    if WAKEUP_FD_OVERFLOWED.swap(false, Ordering::Relaxed) {
+        crate::stdlib::warnings::warn(
+            vm.ctx.exceptions.resource_warning,
+            "signal wakeup fd buffer is full, some signals may be lost".to_owned(),
+            1,
+            vm,
+        )?;
+    }
+
     if !ANY_TRIGGERED.swap(false, Ordering::Acquire) {
         return Ok(());
     }
@@ -122,3 +139,39 @@ pub fn user_signal_channel() -> (UserSignalSender, UserSignalReceiver) {
     let (tx, rx) = mpsc::channel();
     (UserSignalSender { tx }, UserSignalReceiver { rx })
 }
+
+/// A thread-safe handle that can interrupt a running [`VirtualMachine`] from
+/// another thread.
+///
+/// `VirtualMachine` is `!Send` by default (it's full of `PyObjectRef`s), so
+/// nothing about it can be shared across threads directly. This handle
+/// sidesteps that by going through the same [`UserSignalSender`] channel a
+/// real OS signal uses: it queues a closure that gets run on the VM's own
+/// thread the next time the eval loop reaches its periodic checkpoint (see
+/// [`check_signals`]), rather than touching the VM from the calling thread.
+/// Build one with [`crate::Interpreter::with_init_and_handle`].
+#[derive(Clone, Debug)]
+pub struct InterpreterHandle {
+    tx: UserSignalSender,
+}
+
+impl InterpreterHandle {
+    pub fn new(tx: UserSignalSender) -> Self {
+        Self { tx }
+    }
+
+    /// Interrupt the VM with a `KeyboardInterrupt`, as if the user had
+    /// pressed Ctrl-C.
+    pub fn interrupt(&self) -> Result<(), UserSignalSendError> {
+        self.raise(|vm| vm.new_exception_empty(vm.ctx.exceptions.keyboard_interrupt.to_owned()))
+    }
+
+    /// Interrupt the VM with an exception built from `make_exc`, which runs
+    /// on the VM's thread once the signal is delivered.
+    pub fn raise(
+        &self,
+        make_exc: impl FnOnce(&VirtualMachine) -> crate::exceptions::PyBaseExceptionRef + Send + 'static,
+    ) -> Result<(), UserSignalSendError> {
+        self.tx.send(Box::new(move |vm| Err(make_exc(vm))))
+    }
+}
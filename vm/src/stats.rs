@@ -0,0 +1,58 @@
+//! Lightweight interpreter statistics, gated behind the `pystats` feature.
+//!
+//! Modeled on CPython's `--enable-pystats` build: rather than reaching for a
+//! profiler every time someone wants to optimize the eval loop, the
+//! interpreter itself keeps a running tally of opcode frequencies. The
+//! counts are dumped to stderr (or a file named by `PYSTATS_FILE`) when the
+//! interpreter is finalized (`Interpreter::finalize`), if the `PYSTATS`
+//! environment variable is set.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+static OPCODE_COUNTS: Mutex<Option<HashMap<String, u64>>> = Mutex::new(None);
+
+/// Record one execution of `opcode`. Called from the eval loop for every
+/// instruction when the `pystats` feature is enabled; cheap enough (a
+/// mutex-guarded hashmap bump) to leave compiled in behind the feature flag
+/// without needing a separate runtime toggle.
+pub fn record_instruction(opcode: &str) {
+    let mut counts = OPCODE_COUNTS.lock().unwrap();
+    *counts
+        .get_or_insert_with(HashMap::new)
+        .entry(opcode.to_owned())
+        .or_insert(0) += 1;
+}
+
+/// Print the accumulated opcode histogram if `PYSTATS` is set in the
+/// environment. Intended to be called once, near interpreter shutdown.
+pub fn dump() {
+    if std::env::var_os("PYSTATS").is_none() {
+        return;
+    }
+    let counts = OPCODE_COUNTS.lock().unwrap();
+    let Some(counts) = counts.as_ref() else {
+        return;
+    };
+    let mut entries: Vec<_> = counts.iter().collect();
+    entries.sort_by(|a, b| b.1.cmp(a.1));
+
+    let report = |out: &mut dyn std::io::Write| -> std::io::Result<()> {
+        writeln!(out, "=== pystats: opcode execution counts ===")?;
+        for (opcode, count) in &entries {
+            writeln!(out, "{count:>12}  {opcode}")?;
+        }
+        Ok(())
+    };
+
+    match std::env::var_os("PYSTATS_FILE") {
+        Some(path) => {
+            if let Ok(mut file) = std::fs::File::create(path) {
+                let _ = report(&mut file);
+            }
+        }
+        None => {
+            let _ = report(&mut std::io::stderr());
+        }
+    }
+}
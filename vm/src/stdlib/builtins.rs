@@ -72,7 +72,7 @@ mod builtins {
     }
 
     #[pyfunction]
-    fn bin(x: PyIntRef) -> String {
+    fn bin(x: ArgIndex) -> String {
         let x = x.as_bigint();
         if x.is_negative() {
             format!("-0b{:b}", x.abs())
@@ -130,6 +130,14 @@ mod builtins {
 
             let mode_str = args.mode.as_str();
 
+            vm.audit(
+                "compile",
+                vec![
+                    args.source.clone(),
+                    vm.new_pyobj(args.filename.to_string_lossy().into_owned()),
+                ],
+            )?;
+
             let optimize: i32 = args.optimize.map_or(Ok(-1), |v| v.try_to_primitive(vm))?;
             let optimize: u8 = if optimize == -1 {
                 vm.state.settings.optimize
@@ -385,6 +393,8 @@ mod builtins {
             )));
         }
 
+        vm.audit("exec", vec![code_obj.clone().into()])?;
+
         // Run the code:
         vm.run_code_obj(code_obj, scope)
     }
@@ -1032,6 +1042,7 @@ pub fn init_module(vm: &VirtualMachine, module: &Py<PyModule>) {
     let ctx = &vm.ctx;
 
     crate::protocol::VecBuffer::make_class(&vm.ctx);
+    crate::py_future::PyFuture::make_class(&vm.ctx);
 
     builtins::extend_module(vm, module).unwrap();
 
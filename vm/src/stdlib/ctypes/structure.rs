@@ -11,6 +11,8 @@ use std::fmt::Debug;
 #[pyclass(module = "_ctypes", name = "Structure", base = "PyCData")]
 #[derive(PyPayload, Debug)]
 pub struct PyCStructure {
+    // the declared type of each field, keyed by field name; kept around for
+    // when field assignment grows type-checking against `_fields_`
     #[allow(dead_code)]
     field_data: PyRwLock<HashMap<String, PyObjectRef>>,
     data: PyRwLock<HashMap<String, PyObjectRef>>,
@@ -29,6 +31,7 @@ impl Constructor for PyCStructure {
             .ok_or_else(|| vm.new_type_error("Structure _fields_ attribute must be a list"))?;
         let fields = fields.borrow_vec();
         let mut field_data = HashMap::new();
+        let mut data = HashMap::new();
         for field in fields.iter() {
             let field = field
                 .downcast_ref::<PyTuple>()
@@ -39,9 +42,19 @@ impl Constructor for PyCStructure {
                 .downcast_ref::<PyStr>()
                 .ok_or_else(|| vm.new_type_error("Field name must be a string"))?;
             let typ = field.get(1).unwrap().clone();
+            // each field starts out holding its declared type's zero value,
+            // same as a bare `c_int()` or `c_char()` would construct
+            let default = typ.call((), vm)?;
             field_data.insert(name.to_string(), typ);
+            data.insert(name.to_string(), default);
         }
-        todo!("Implement PyCStructure::py_new")
+        Ok(PyObjectRef::from(
+            PyCStructure {
+                field_data: PyRwLock::new(field_data),
+                data: PyRwLock::new(data),
+            }
+            .into_ref_with_type(vm, cls)?,
+        ))
     }
 }
 
@@ -56,5 +69,5 @@ impl GetAttr for PyCStructure {
     }
 }
 
-#[pyclass(flags(BASETYPE, IMMUTABLETYPE))]
+#[pyclass(flags(BASETYPE, IMMUTABLETYPE), with(Constructor, GetAttr))]
 impl PyCStructure {}
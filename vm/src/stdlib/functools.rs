@@ -250,25 +250,29 @@ mod _functools {
             let mut combined_args = inner.args.as_slice().to_vec();
             combined_args.extend_from_slice(&args.args);
 
-            // Merge keywords from self.keywords and args.kwargs
-            let mut final_kwargs = IndexMap::new();
-
-            // Add keywords from self.keywords
-            for (key, value) in &*inner.keywords {
-                let key_str = key
-                    .downcast::<crate::builtins::PyStr>()
-                    .map_err(|_| vm.new_type_error("keywords must be strings"))?;
-                final_kwargs.insert(key_str.as_str().to_owned(), value);
-            }
-
-            // Add keywords from args.kwargs (these override self.keywords)
-            for (key, value) in args.kwargs {
-                final_kwargs.insert(key, value);
-            }
+            // The common callback-heavy shape is a partial with no bound
+            // keywords at all, so skip building an intermediate merged map
+            // (and re-interning its keys) in that case.
+            let final_kwargs = if inner.keywords.is_empty() {
+                KwArgs::new(args.kwargs)
+            } else {
+                let mut merged = IndexMap::with_capacity(inner.keywords.__len__() + args.kwargs.len());
+                for (key, value) in &*inner.keywords {
+                    let key_str = key
+                        .downcast::<crate::builtins::PyStr>()
+                        .map_err(|_| vm.new_type_error("keywords must be strings"))?;
+                    merged.insert(key_str.as_str().to_owned(), value);
+                }
+                // call-site keywords override the ones bound on the partial
+                for (key, value) in args.kwargs {
+                    merged.insert(key, value);
+                }
+                KwArgs::new(merged)
+            };
 
             inner
                 .func
-                .call(FuncArgs::new(combined_args, KwArgs::new(final_kwargs)), vm)
+                .call(FuncArgs::new(combined_args, final_kwargs), vm)
         }
     }
 
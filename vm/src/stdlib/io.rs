@@ -421,7 +421,7 @@ mod _io {
 
         #[pymethod]
         fn fileno(zelf: PyObjectRef, vm: &VirtualMachine) -> PyResult {
-            _unsupported(vm, &zelf, "truncate")
+            _unsupported(vm, &zelf, "fileno")
         }
 
         #[pyattr]
@@ -3952,6 +3952,11 @@ mod _io {
             .parse::<Mode>()
             .map_err(|e| vm.new_value_error(e.error_msg(mode_string)))?;
 
+        vm.audit(
+            "open",
+            vec![file.clone(), vm.new_pyobj(mode_string.to_owned())],
+        )?;
+
         if let EncodeMode::Bytes = mode.encode {
             let msg = if opts.encoding.is_some() {
                 Some("binary mode doesn't take an encoding argument")
@@ -3974,6 +3979,20 @@ mod _io {
                 .map_err(|_| crate::stdlib::os::errno_err(vm))?;
         }
 
+        // If an embedder installed a virtual filesystem (see `crate::vfs`),
+        // serve plain read-only opens from it instead of the real OS -- this
+        // is also how the import machinery ends up reading bundled modules.
+        if let Some(fs) = vm.filesystem.as_deref()
+            && matches!(mode.file, FileMode::Read)
+            && !mode.plus
+            && opts.opener.is_none()
+            && let Ok(ospath) = crate::ospath::OsPath::try_from_object(vm, file.clone())
+        {
+            return open_from_filesystem(fs, ospath.as_path(), &mode, &opts, mode_string, vm);
+        }
+
+        crate::stdlib::os::check_filesystem_allowed(vm)?;
+
         // Construct a FileIO (subclass of RawIOBase)
         // This is subsequently consumed by a Buffered Class.
         let file_io_class: &Py<PyType> = {
@@ -4049,6 +4068,46 @@ mod _io {
         }
     }
 
+    /// Serve a read-only `io.open()` through an embedder-installed
+    /// [`FileSystem`](crate::vfs::FileSystem) instead of the real OS. `BytesIO`
+    /// is already buffered, so unlike the `FileIO` path above this skips
+    /// straight past the `BufferedReader` wrapping step.
+    fn open_from_filesystem(
+        fs: &dyn crate::vfs::FileSystem,
+        path: &std::path::Path,
+        mode: &Mode,
+        opts: &OpenArgs,
+        mode_string: &str,
+        vm: &VirtualMachine,
+    ) -> PyResult {
+        let data = fs.read_file(path).map_err(|e| e.into_pyexception(vm))?;
+        let buffered = PyType::call(
+            BytesIO::static_type(),
+            (vm.ctx.new_bytes(data),).into_args(vm),
+            vm,
+        )?;
+
+        match mode.encode {
+            EncodeMode::Bytes => Ok(buffered),
+            EncodeMode::Text => {
+                let wrapper = PyType::call(
+                    TextIOWrapper::static_type(),
+                    (
+                        buffered,
+                        opts.encoding.clone(),
+                        opts.errors.clone(),
+                        opts.newline.clone(),
+                        false,
+                    )
+                        .into_args(vm),
+                    vm,
+                )?;
+                wrapper.set_attr("mode", vm.new_pyobj(mode_string.to_owned()), vm)?;
+                Ok(wrapper)
+            }
+        }
+    }
+
     rustpython_common::static_cell! {
         pub(super) static UNSUPPORTED_OPERATION: PyTypeRef;
     }
@@ -23,6 +23,18 @@ mod sysconfigdata;
 #[cfg(feature = "threading")]
 pub mod thread;
 pub mod time;
+// RUSTPYTHON: NOT IMPLEMENTED. There is no native `_datetime` module here --
+// the request for one (tz-aware arithmetic, fold handling, a `zoneinfo`
+// fast path) is still outstanding, not delivered by this comment. Same
+// reasoning as the missing `_decimal` one (see `rustpython_stdlib`'s
+// lib.rs): `Lib/datetime.py` does `from _datetime import *` and only falls
+// back to `_pydatetime` on `ImportError`, so a partial native
+// `date`/`time`/`datetime`/`timedelta` implementation -- missing fold
+// handling, tz-aware arithmetic, or the `zoneinfo` C fast-path hooks --
+// would silently replace a correct slow implementation with a subtly wrong
+// fast one rather than degrade gracefully. Worth doing once it can be a
+// complete drop-in; until then this stays a tracked follow-up, not
+// something to build partway.
 mod typevar;
 pub mod typing;
 pub mod warnings;
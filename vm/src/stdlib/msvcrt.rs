@@ -74,6 +74,43 @@ mod msvcrt {
         Ok(())
     }
 
+    unsafe extern "C" {
+        fn _kbhit() -> i32;
+        fn _ungetch(c: i32) -> i32;
+        fn _ungetwch(c: u16) -> u32;
+    }
+
+    #[pyfunction]
+    fn kbhit() -> bool {
+        unsafe { _kbhit() != 0 }
+    }
+    #[pyfunction]
+    fn ungetch(b: PyRef<PyBytes>, vm: &VirtualMachine) -> PyResult<()> {
+        let &c = b.as_bytes().iter().exactly_one().map_err(|_| {
+            vm.new_type_error("ungetch() argument must be a byte string of length 1")
+        })?;
+        let ret = unsafe { suppress_iph!(_ungetch(c.into())) };
+        if ret == -1 {
+            Err(errno_err(vm))
+        } else {
+            Ok(())
+        }
+    }
+    #[pyfunction]
+    fn ungetwch(s: PyStrRef, vm: &VirtualMachine) -> PyResult<()> {
+        let c = s
+            .as_str()
+            .chars()
+            .exactly_one()
+            .map_err(|_| vm.new_type_error("ungetwch() argument must be a string of length 1"))?;
+        let ret = unsafe { suppress_iph!(_ungetwch(c as u16)) };
+        if ret == 0xFFFF {
+            Err(errno_err(vm))
+        } else {
+            Ok(())
+        }
+    }
+
     unsafe extern "C" {
         fn _setmode(fd: crt_fd::Borrowed<'_>, flags: i32) -> i32;
     }
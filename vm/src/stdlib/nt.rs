@@ -19,7 +19,10 @@ pub(crate) mod module {
         convert::ToPyException,
         function::{Either, OptionalArg},
         ospath::OsPath,
-        stdlib::os::{_os, DirFd, FollowSymlinks, SupportFunc, TargetIsDirectory, errno_err},
+        stdlib::os::{
+            _os, DirFd, FollowSymlinks, SupportFunc, TargetIsDirectory, check_subprocess_allowed,
+            errno_err,
+        },
     };
     use libc::intptr_t;
     use std::os::windows::io::AsRawHandle;
@@ -223,6 +226,8 @@ pub(crate) mod module {
     ) -> PyResult<()> {
         use std::iter::once;
 
+        check_subprocess_allowed(vm)?;
+
         let make_widestring =
             |s: &str| widestring::WideCString::from_os_str(s).map_err(|err| err.to_pyexception(vm));
 
@@ -34,6 +34,39 @@ impl crate::convert::IntoPyException for rustix::io::Errno {
     }
 }
 
+/// Checked by the handful of process-spawning functions (`os.system`,
+/// `os.fork`, `os.exec*`) that live in `os`/`posix`/`nt` rather than in
+/// `_posixsubprocess`/`_multiprocessing`, so `InterpreterConfig::allow_subprocess(false)`
+/// still has teeth even though those modules are always registered.
+pub(crate) fn check_subprocess_allowed(vm: &VirtualMachine) -> PyResult<()> {
+    if vm
+        .state
+        .subprocess_allowed
+        .load(std::sync::atomic::Ordering::Relaxed)
+    {
+        Ok(())
+    } else {
+        Err(vm.new_permission_error("subprocess creation is disabled for this interpreter"))
+    }
+}
+
+/// Checked by the handful of `os`/`posix`/`nt`/`io` functions (`open`,
+/// `remove`/`unlink`, `rename`/`replace`, `mkdir`, `rmdir`, `listdir`,
+/// `io.open`) that touch the real filesystem rather than going through a
+/// deniable module, so `InterpreterConfig::allow_filesystem(false)` still
+/// has teeth even though those modules are always registered.
+pub(crate) fn check_filesystem_allowed(vm: &VirtualMachine) -> PyResult<()> {
+    if vm
+        .state
+        .filesystem_allowed
+        .load(std::sync::atomic::Ordering::Relaxed)
+    {
+        Ok(())
+    } else {
+        Err(vm.new_permission_error("filesystem access is disabled for this interpreter"))
+    }
+}
+
 /// Convert the error stored in the `errno` variable into an Exception
 #[inline]
 pub fn errno_err(vm: &VirtualMachine) -> PyBaseExceptionRef {
@@ -151,7 +184,10 @@ impl ToPyObject for crt_fd::Borrowed<'_> {
 
 #[pymodule(sub)]
 pub(super) mod _os {
-    use super::{DirFd, FollowSymlinks, SupportFunc, errno_err};
+    use super::{
+        DirFd, FollowSymlinks, SupportFunc, check_filesystem_allowed, check_subprocess_allowed,
+        errno_err,
+    };
     use crate::{
         AsObject, Py, PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject,
         builtins::{
@@ -230,6 +266,7 @@ pub(super) mod _os {
 
     #[pyfunction]
     fn open(args: OpenArgs<'_>, vm: &VirtualMachine) -> PyResult<crt_fd::Owned> {
+        check_filesystem_allowed(vm)?;
         os_open(args.path, args.flags, args.mode, args.dir_fd, vm)
     }
 
@@ -291,6 +328,7 @@ pub(super) mod _os {
     #[pyfunction]
     #[pyfunction(name = "unlink")]
     fn remove(path: OsPath, dir_fd: DirFd<'_, 0>, vm: &VirtualMachine) -> PyResult<()> {
+        check_filesystem_allowed(vm)?;
         let [] = dir_fd.0;
         let is_junction = cfg!(windows)
             && fs::metadata(&path).is_ok_and(|meta| meta.file_type().is_dir())
@@ -311,6 +349,7 @@ pub(super) mod _os {
         dir_fd: DirFd<'_, { MKDIR_DIR_FD as usize }>,
         vm: &VirtualMachine,
     ) -> PyResult<()> {
+        check_filesystem_allowed(vm)?;
         let mode = mode.unwrap_or(0o777);
         let c_path = path.clone().into_cstring(vm)?;
         #[cfg(not(target_os = "redox"))]
@@ -335,11 +374,13 @@ pub(super) mod _os {
 
     #[pyfunction]
     fn mkdirs(path: PyStrRef, vm: &VirtualMachine) -> PyResult<()> {
+        check_filesystem_allowed(vm)?;
         fs::create_dir_all(path.as_str()).map_err(|err| err.into_pyexception(vm))
     }
 
     #[pyfunction]
     fn rmdir(path: OsPath, dir_fd: DirFd<'_, 0>, vm: &VirtualMachine) -> PyResult<()> {
+        check_filesystem_allowed(vm)?;
         let [] = dir_fd.0;
         fs::remove_dir(&path).map_err(|err| IOErrorBuilder::with_filename(&err, path, vm))
     }
@@ -351,6 +392,7 @@ pub(super) mod _os {
         path: OptionalArg<OsPathOrFd<'_>>,
         vm: &VirtualMachine,
     ) -> PyResult<Vec<PyObjectRef>> {
+        check_filesystem_allowed(vm)?;
         let path = path.unwrap_or_else(|| OsPathOrFd::Path(OsPath::new_str(".")));
         let list = match path {
             OsPathOrFd::Path(path) => {
@@ -960,6 +1002,7 @@ pub(super) mod _os {
     #[pyfunction]
     #[pyfunction(name = "replace")]
     fn rename(src: OsPath, dst: OsPath, vm: &VirtualMachine) -> PyResult<()> {
+        check_filesystem_allowed(vm)?;
         fs::rename(&src.path, &dst.path).map_err(|err| {
             IOErrorBuilder::new(&err)
                 .filename(src)
@@ -1059,6 +1102,7 @@ pub(super) mod _os {
     #[cfg(any(unix, windows))]
     #[pyfunction]
     fn system(command: PyStrRef, vm: &VirtualMachine) -> PyResult<i32> {
+        check_subprocess_allowed(vm)?;
         let cstr = command.to_cstring(vm)?;
         let x = unsafe { libc::system(cstr.as_ptr()) };
         Ok(x)
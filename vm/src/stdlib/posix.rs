@@ -20,6 +20,13 @@ pub(crate) fn make_module(vm: &VirtualMachine) -> PyRef<PyModule> {
     module
 }
 
+/// Run the `os.register_at_fork(before=...)` hooks. Called around any raw
+/// `fork()`, not just `os.fork()` itself, so embedders forking through e.g.
+/// `subprocess.Popen` still get a chance to quiesce locks beforehand.
+pub use module::py_os_before_fork;
+/// Run the `os.register_at_fork(after_in_parent=...)` hooks.
+pub use module::py_os_after_fork_parent;
+
 #[pymodule(name = "posix", with(super::os::_os))]
 pub mod module {
     use crate::{
@@ -29,7 +36,8 @@ pub mod module {
         function::{Either, KwArgs, OptionalArg},
         ospath::{IOErrorBuilder, OsPath, OsPathOrFd},
         stdlib::os::{
-            _os, DirFd, FollowSymlinks, SupportFunc, TargetIsDirectory, errno_err, fs_metadata,
+            _os, DirFd, FollowSymlinks, SupportFunc, TargetIsDirectory, check_subprocess_allowed,
+            errno_err, fs_metadata,
         },
         types::{Constructor, Representable},
         utils::ToCString,
@@ -641,7 +649,7 @@ pub mod module {
         }
     }
 
-    fn py_os_before_fork(vm: &VirtualMachine) {
+    pub(crate) fn py_os_before_fork(vm: &VirtualMachine) {
         let before_forkers: Vec<PyObjectRef> = vm.state.before_forkers.lock().clone();
         // functions must be executed in reversed order as they are registered
         // only for before_forkers, refer: test_register_at_fork in test_posix
@@ -654,13 +662,14 @@ pub mod module {
         run_at_forkers(after_forkers_child, false, vm);
     }
 
-    fn py_os_after_fork_parent(vm: &VirtualMachine) {
+    pub(crate) fn py_os_after_fork_parent(vm: &VirtualMachine) {
         let after_forkers_parent: Vec<PyObjectRef> = vm.state.after_forkers_parent.lock().clone();
         run_at_forkers(after_forkers_parent, false, vm);
     }
 
     #[pyfunction]
-    fn fork(vm: &VirtualMachine) -> i32 {
+    fn fork(vm: &VirtualMachine) -> PyResult<i32> {
+        check_subprocess_allowed(vm)?;
         let pid: i32;
         py_os_before_fork(vm);
         unsafe {
@@ -671,7 +680,7 @@ pub mod module {
         } else {
             py_os_after_fork_parent(vm);
         }
-        pid
+        Ok(pid)
     }
 
     #[cfg(not(target_os = "redox"))]
@@ -1095,6 +1104,7 @@ pub mod module {
         argv: Either<PyListRef, PyTupleRef>,
         vm: &VirtualMachine,
     ) -> PyResult<()> {
+        check_subprocess_allowed(vm)?;
         let path = path.into_cstring(vm)?;
 
         let argv = vm.extract_elements_with(argv.as_ref(), |obj| {
@@ -1121,6 +1131,7 @@ pub mod module {
         env: PyDictRef,
         vm: &VirtualMachine,
     ) -> PyResult<()> {
+        check_subprocess_allowed(vm)?;
         let path = path.into_cstring(vm)?;
 
         let argv = vm.extract_elements_with(argv.as_ref(), |obj| {
@@ -1317,6 +1328,26 @@ pub mod module {
         Ok((r.master, r.slave))
     }
 
+    #[cfg(not(target_os = "redox"))]
+    #[pyfunction]
+    fn forkpty(vm: &VirtualMachine) -> PyResult<(i32, OwnedFd)> {
+        check_subprocess_allowed(vm)?;
+        py_os_before_fork(vm);
+        let result =
+            unsafe { nix::pty::forkpty(None, None) }.map_err(|err| err.into_pyexception(vm))?;
+        super::set_inheritable(result.master.as_fd(), false).map_err(|e| e.into_pyexception(vm))?;
+        match result.fork_result {
+            nix::unistd::ForkResult::Child => {
+                py_os_after_fork_child(vm);
+                Ok((0, result.master))
+            }
+            nix::unistd::ForkResult::Parent { child } => {
+                py_os_after_fork_parent(vm);
+                Ok((child.as_raw(), result.master))
+            }
+        }
+    }
+
     #[pyfunction]
     fn ttyname(fd: BorrowedFd<'_>, vm: &VirtualMachine) -> PyResult {
         let name = unistd::ttyname(fd).map_err(|e| e.into_pyexception(vm))?;
@@ -1505,6 +1536,8 @@ pub mod module {
         fn spawn(self, spawnp: bool, vm: &VirtualMachine) -> PyResult<libc::pid_t> {
             use crate::TryFromBorrowedObject;
 
+            check_subprocess_allowed(vm)?;
+
             let path = self
                 .path
                 .clone()
@@ -21,6 +21,9 @@ pub(crate) mod _signal {
     use crate::{PyObjectRef, PyResult, VirtualMachine, signal};
     use std::sync::atomic::{self, Ordering};
 
+    #[cfg(all(unix, not(target_os = "redox")))]
+    use crate::{builtins::PySet, convert::TryFromObject};
+
     #[cfg(any(unix, windows))]
     use libc::sighandler_t;
     #[allow(non_camel_case_types)]
@@ -109,6 +112,14 @@ pub(crate) mod _signal {
     #[pyattr]
     use libc::{SIGPWR, SIGSTKFLT};
 
+    #[cfg(all(unix, not(target_os = "redox")))]
+    #[pyattr]
+    use libc::{ITIMER_PROF, ITIMER_REAL, ITIMER_VIRTUAL};
+
+    #[cfg(all(unix, not(target_os = "redox")))]
+    #[pyattr]
+    use libc::{SIG_BLOCK, SIG_SETMASK, SIG_UNBLOCK};
+
     #[cfg(any(unix, windows))]
     pub(super) fn init_signal_handlers(
         module: &Py<crate::builtins::PyModule>,
@@ -227,10 +238,11 @@ pub(crate) mod _signal {
         warn_on_full_buffer: bool,
     }
 
+    static WARN_ON_FULL_BUFFER: atomic::AtomicBool = atomic::AtomicBool::new(true);
+
     #[pyfunction]
     fn set_wakeup_fd(args: SetWakeupFdArgs, vm: &VirtualMachine) -> PyResult<WakeupFdRaw> {
-        // TODO: implement warn_on_full_buffer
-        let _ = args.warn_on_full_buffer;
+        WARN_ON_FULL_BUFFER.store(args.warn_on_full_buffer, Ordering::Relaxed);
         #[cfg(windows)]
         let fd = args.fd.0;
         #[cfg(not(windows))]
@@ -302,6 +314,129 @@ pub(crate) mod _signal {
         }
     }
 
+    #[cfg(unix)]
+    #[pyfunction]
+    fn raise_signal(signalnum: i32, vm: &VirtualMachine) -> PyResult<()> {
+        signal::assert_in_range(signalnum, vm)?;
+        signal::check_signals(vm)?;
+        if unsafe { libc::raise(signalnum) } != 0 {
+            Err(crate::stdlib::os::errno_err(vm))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[cfg(all(unix, not(target_os = "redox")))]
+    #[pyfunction]
+    fn pthread_sigmask(how: i32, mask: PyObjectRef, vm: &VirtualMachine) -> PyResult<PyObjectRef> {
+        use nix::sys::signal::{Signal, SigSet, SigmaskHow};
+
+        let how = match how {
+            libc::SIG_BLOCK => SigmaskHow::SIG_BLOCK,
+            libc::SIG_UNBLOCK => SigmaskHow::SIG_UNBLOCK,
+            libc::SIG_SETMASK => SigmaskHow::SIG_SETMASK,
+            _ => return Err(vm.new_value_error("invalid how")),
+        };
+
+        let signums = vm.extract_elements_with(&mask, |obj| i32::try_from_object(vm, obj))?;
+        let mut set = SigSet::empty();
+        for signum in signums {
+            signal::assert_in_range(signum, vm)?;
+            let sig = Signal::try_from(signum).map_err(|_| {
+                vm.new_value_error(format!("signal number {signum} out of range"))
+            })?;
+            set.add(sig);
+        }
+
+        let mut old_set = SigSet::empty();
+        nix::sys::signal::pthread_sigmask(how, Some(&set), Some(&mut old_set))
+            .map_err(|e| e.into_pyexception(vm))?;
+
+        let result = PySet::default().into_ref(&vm.ctx);
+        for signum in 1..NSIG as i32 {
+            if let Ok(sig) = Signal::try_from(signum) {
+                if old_set.contains(sig) {
+                    result.add(vm.new_pyobj(signum), vm)?;
+                }
+            }
+        }
+        Ok(result.into())
+    }
+
+    #[cfg(all(unix, not(target_os = "redox")))]
+    #[pyfunction]
+    fn sigwait(sigset: PyObjectRef, vm: &VirtualMachine) -> PyResult<i32> {
+        use nix::sys::signal::{Signal, SigSet};
+
+        let signums = vm.extract_elements_with(&sigset, |obj| i32::try_from_object(vm, obj))?;
+        let mut set = SigSet::empty();
+        for signum in signums {
+            signal::assert_in_range(signum, vm)?;
+            let sig = Signal::try_from(signum).map_err(|_| {
+                vm.new_value_error(format!("signal number {signum} out of range"))
+            })?;
+            set.add(sig);
+        }
+
+        let sig = nix::sys::signal::sigwait(&set).map_err(|e| e.into_pyexception(vm))?;
+        Ok(sig as i32)
+    }
+
+    #[cfg(all(unix, not(target_os = "redox")))]
+    fn timeval_to_duration(tv: libc::timeval) -> std::time::Duration {
+        std::time::Duration::new(tv.tv_sec.max(0) as u64, (tv.tv_usec.max(0) as u32) * 1000)
+    }
+
+    #[cfg(all(unix, not(target_os = "redox")))]
+    fn duration_to_timeval(secs: f64) -> libc::timeval {
+        let secs = secs.max(0.0);
+        libc::timeval {
+            tv_sec: secs.trunc() as libc::time_t,
+            tv_usec: (secs.fract() * 1_000_000.0).round() as libc::suseconds_t,
+        }
+    }
+
+    #[cfg(all(unix, not(target_os = "redox")))]
+    #[derive(FromArgs)]
+    struct SetitimerArgs {
+        #[pyarg(positional)]
+        which: i32,
+        #[pyarg(positional)]
+        seconds: f64,
+        #[pyarg(positional, default = 0.0)]
+        interval: f64,
+    }
+
+    #[cfg(all(unix, not(target_os = "redox")))]
+    #[pyfunction]
+    fn setitimer(args: SetitimerArgs, vm: &VirtualMachine) -> PyResult<(f64, f64)> {
+        let new = libc::itimerval {
+            it_interval: duration_to_timeval(args.interval),
+            it_value: duration_to_timeval(args.seconds),
+        };
+        let mut old: libc::itimerval = unsafe { std::mem::zeroed() };
+        if unsafe { libc::setitimer(args.which, &new, &mut old) } != 0 {
+            return Err(crate::stdlib::os::errno_err(vm));
+        }
+        Ok((
+            timeval_to_duration(old.it_value).as_secs_f64(),
+            timeval_to_duration(old.it_interval).as_secs_f64(),
+        ))
+    }
+
+    #[cfg(all(unix, not(target_os = "redox")))]
+    #[pyfunction]
+    fn getitimer(which: i32, vm: &VirtualMachine) -> PyResult<(f64, f64)> {
+        let mut old: libc::itimerval = unsafe { std::mem::zeroed() };
+        if unsafe { libc::getitimer(which, &mut old) } != 0 {
+            return Err(crate::stdlib::os::errno_err(vm));
+        }
+        Ok((
+            timeval_to_duration(old.it_value).as_secs_f64(),
+            timeval_to_duration(old.it_interval).as_secs_f64(),
+        ))
+    }
+
     #[cfg(any(unix, windows))]
     pub extern "C" fn run_signal(signum: i32) {
         signal::TRIGGERS[signum as usize].store(true, Ordering::Relaxed);
@@ -321,8 +456,10 @@ pub(crate) mod _signal {
                 };
                 return;
             }
-            let _res = unsafe { libc::write(wakeup_fd as _, &sigbyte as *const u8 as *const _, 1) };
-            // TODO: handle _res < 1, support warn_on_full_buffer
+            let res = unsafe { libc::write(wakeup_fd as _, &sigbyte as *const u8 as *const _, 1) };
+            if res < 1 && WARN_ON_FULL_BUFFER.load(Ordering::Relaxed) {
+                signal::WAKEUP_FD_OVERFLOWED.store(true, Ordering::Relaxed);
+            }
         }
     }
 }
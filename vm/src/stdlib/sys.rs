@@ -149,6 +149,30 @@ mod sys {
         )
     }
 
+    /// Every module the `Lib/` directory provides, generated at build time
+    /// from the actual `Lib/` listing (see `build.rs`), unioned with the
+    /// natively compiled-in modules from `builtin_module_names` -- same
+    /// relationship CPython's `sys.stdlib_module_names` has to
+    /// `sys.builtin_module_names`.
+    #[pyattr]
+    fn stdlib_module_names(vm: &VirtualMachine) -> PyTupleRef {
+        static PURE_PYTHON_NAMES: &[&str] =
+            include!(concat!(env!("OUT_DIR"), "/stdlib_module_names.rs"));
+
+        let mut module_names: Vec<&str> = PURE_PYTHON_NAMES.to_vec();
+        module_names.extend(vm.state.module_inits.keys().map(String::as_str));
+        module_names.push("sys");
+        module_names.push("builtins");
+        module_names.sort_unstable();
+        module_names.dedup();
+        vm.ctx.new_tuple(
+            module_names
+                .into_iter()
+                .map(|n| vm.ctx.new_str(n).into())
+                .collect(),
+        )
+    }
+
     #[pyattr]
     fn byteorder(vm: &VirtualMachine) -> PyStrRef {
         // https://doc.rust-lang.org/reference/conditional-compilation.html#target_endian
@@ -272,7 +296,10 @@ mod sys {
 
     #[pyattr]
     fn pycache_prefix(vm: &VirtualMachine) -> PyObjectRef {
-        vm.ctx.none()
+        match &vm.state.settings.pycache_prefix {
+            Some(prefix) => vm.ctx.new_str(prefix.as_str()).into(),
+            None => vm.ctx.none(),
+        }
     }
 
     #[pyattr]
@@ -327,8 +354,13 @@ mod sys {
     }
 
     #[pyfunction]
-    fn audit(_args: FuncArgs) {
-        // TODO: sys.audit implementation
+    fn audit(event: PyStrRef, args: PosArgs, vm: &VirtualMachine) -> PyResult<()> {
+        vm.audit(event.as_str(), args.into_vec())
+    }
+
+    #[pyfunction]
+    fn addaudithook(hook: PyObjectRef, vm: &VirtualMachine) {
+        vm.add_audit_hook(hook);
     }
 
     #[pyfunction]
@@ -466,6 +498,11 @@ mod sys {
         obj.strong_count()
     }
 
+    #[pyfunction]
+    fn getallocatedblocks(vm: &VirtualMachine) -> usize {
+        vm.allocated_blocks()
+    }
+
     #[pyfunction]
     fn getrecursionlimit(vm: &VirtualMachine) -> usize {
         vm.recursion_limit.get()
@@ -19,6 +19,14 @@ pub(crate) mod _sysconfigdata {
             // enough for tests to stop expecting urandom() to fail after restricting file resources
             "HAVE_GETRANDOM" => 1,
         }
+        // Same default search path CPython's configure script bakes in on
+        // Unix; without it `zoneinfo.TZPATH` is empty and every `ZoneInfo()`
+        // lookup fails with `ZoneInfoNotFoundError` even when the system has
+        // a perfectly good tzdata installed.
+        #[cfg(all(unix, not(target_os = "android")))]
+        sysvars! {
+            "TZPATH" => "/usr/share/zoneinfo:/usr/lib/zoneinfo:/usr/share/lib/zoneinfo:/etc/zoneinfo",
+        }
         include!(concat!(env!("OUT_DIR"), "/env_vars.rs"));
         vars
     }
@@ -267,6 +267,12 @@ mod decl {
     ) -> PyResult<DateTime<chrono::offset::Utc>> {
         let timestamp = match value {
             Either::A(float) => {
+                if float.is_nan() {
+                    return Err(vm.new_value_error("Invalid value NaN (not a number)"));
+                }
+                if !float.is_finite() {
+                    return Err(vm.new_overflow_error("cannot convert float infinity to integer"));
+                }
                 let secs = float.trunc() as i64;
                 let nano_secs = (float.fract() * 1e9) as u32;
                 DateTime::<chrono::offset::Utc>::from_timestamp(secs, nano_secs)
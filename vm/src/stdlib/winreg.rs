@@ -30,8 +30,10 @@ pub(crate) fn make_module(vm: &VirtualMachine) -> PyRef<PyModule> {
 mod winreg {
     use crate::common::lock::{PyRwLock, PyRwLockReadGuard, PyRwLockWriteGuard};
     use crate::{
-        PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject, VirtualMachine, builtins::PyStrRef,
+        PyObjectRef, PyPayload, PyRef, PyResult, TryFromObject, VirtualMachine,
+        builtins::{PyIntRef, PyListRef, PyStrRef},
         convert::ToPyException,
+        function::ArgBytesLike,
     };
     use ::winreg::{RegKey, RegValue, enums::RegType};
     use std::mem::ManuallyDrop;
@@ -274,6 +276,100 @@ mod winreg {
             .map_err(|e| e.to_pyexception(vm))
     }
 
+    #[pyfunction]
+    fn DeleteValue(key: Hkey, value: PyStrRef, vm: &VirtualMachine) -> PyResult<()> {
+        key.with_key(|k| k.delete_value(value.as_str()))
+            .map_err(|e| e.to_pyexception(vm))
+    }
+
+    #[derive(FromArgs)]
+    struct SetValueExArgs {
+        key: Hkey,
+        value_name: Option<PyStrRef>,
+        #[pyarg(positional)]
+        _reserved: PyObjectRef,
+        #[pyarg(positional)]
+        typ: u32,
+        #[pyarg(positional)]
+        value: PyObjectRef,
+    }
+
+    #[pyfunction]
+    fn SetValueEx(args: SetValueExArgs, vm: &VirtualMachine) -> PyResult<()> {
+        let SetValueExArgs {
+            key,
+            value_name,
+            typ,
+            value,
+            ..
+        } = args;
+        let value_name = value_name.as_ref().map_or("", |s| s.as_str());
+        let regval = py_to_reg(value, typ, vm)?;
+        key.with_key(|k| k.set_raw_value(value_name, &regval))
+            .map_err(|e| e.to_pyexception(vm))
+    }
+
+    fn wide_from_str(s: &str) -> Vec<u8> {
+        let mut wide: Vec<u16> = s.encode_utf16().collect();
+        wide.push(0);
+        wide.iter().flat_map(|w| w.to_ne_bytes()).collect()
+    }
+
+    fn py_to_reg(value: PyObjectRef, typ: u32, vm: &VirtualMachine) -> PyResult<RegValue> {
+        // RegType's variants line up 1:1 with the REG_* constants above; anything we don't
+        // recognize is stored as raw bytes, same as REG_BINARY.
+        let vtype = match typ {
+            REG_NONE => RegType::REG_NONE,
+            REG_SZ => RegType::REG_SZ,
+            REG_EXPAND_SZ => RegType::REG_EXPAND_SZ,
+            REG_MULTI_SZ => RegType::REG_MULTI_SZ,
+            REG_DWORD => RegType::REG_DWORD,
+            REG_DWORD_BIG_ENDIAN => RegType::REG_DWORD_BIG_ENDIAN,
+            REG_LINK => RegType::REG_LINK,
+            REG_QWORD => RegType::REG_QWORD,
+            REG_RESOURCE_LIST => RegType::REG_RESOURCE_LIST,
+            REG_FULL_RESOURCE_DESCRIPTOR => RegType::REG_FULL_RESOURCE_DESCRIPTOR,
+            REG_RESOURCE_REQUIREMENTS_LIST => RegType::REG_RESOURCE_REQUIREMENTS_LIST,
+            _ => RegType::REG_BINARY,
+        };
+        let bytes = match vtype {
+            RegType::REG_DWORD => {
+                let i = PyIntRef::try_from_object(vm, value)?
+                    .try_to_primitive::<u32>(vm)
+                    .map_err(|_| vm.new_overflow_error("int too big for REG_DWORD"))?;
+                i.to_ne_bytes().to_vec()
+            }
+            RegType::REG_DWORD_BIG_ENDIAN => {
+                let i = PyIntRef::try_from_object(vm, value)?
+                    .try_to_primitive::<u32>(vm)
+                    .map_err(|_| vm.new_overflow_error("int too big for REG_DWORD_BIG_ENDIAN"))?;
+                i.to_be_bytes().to_vec()
+            }
+            RegType::REG_QWORD => {
+                let i = PyIntRef::try_from_object(vm, value)?
+                    .try_to_primitive::<u64>(vm)
+                    .map_err(|_| vm.new_overflow_error("int too big for REG_QWORD"))?;
+                i.to_ne_bytes().to_vec()
+            }
+            RegType::REG_SZ | RegType::REG_EXPAND_SZ => {
+                let s = PyStrRef::try_from_object(vm, value)?;
+                wide_from_str(s.as_str())
+            }
+            RegType::REG_MULTI_SZ => {
+                let list = PyListRef::try_from_object(vm, value)?;
+                let mut bytes = Vec::new();
+                for item in list.borrow_vec().iter() {
+                    let s = PyStrRef::try_from_object(vm, item.clone())?;
+                    bytes.extend(wide_from_str(s.as_str()));
+                }
+                bytes.extend([0u8, 0u8]);
+                bytes
+            }
+            _ => ArgBytesLike::try_from_object(vm, value)?.borrow_buf().to_vec(),
+        };
+        Ok(RegValue { bytes, vtype })
+    }
+
     fn reg_to_py(value: RegValue, vm: &VirtualMachine) -> PyResult {
         macro_rules! bytes_to_int {
             ($int:ident, $f:ident, $name:ident) => {{
@@ -224,6 +224,22 @@ pub(crate) fn contains_wrapper(
     ret.try_to_bool(vm)
 }
 
+fn concat_wrapper(obj: &PyObject, other: &PyObject, vm: &VirtualMachine) -> PyResult {
+    vm.call_special_method(obj, identifier!(vm, __add__), (other,))
+}
+
+fn inplace_concat_wrapper(obj: &PyObject, other: &PyObject, vm: &VirtualMachine) -> PyResult {
+    vm.call_special_method(obj, identifier!(vm, __iadd__), (other,))
+}
+
+fn repeat_wrapper(obj: &PyObject, n: isize, vm: &VirtualMachine) -> PyResult {
+    vm.call_special_method(obj, identifier!(vm, __mul__), (n,))
+}
+
+fn inplace_repeat_wrapper(obj: &PyObject, n: isize, vm: &VirtualMachine) -> PyResult {
+    vm.call_special_method(obj, identifier!(vm, __imul__), (n,))
+}
+
 macro_rules! number_unary_op_wrapper {
     ($name:ident) => {
         |a, vm| vm.call_special_method(a.deref(), identifier!(vm, $name), ())
@@ -280,7 +296,7 @@ fn hash_wrapper(zelf: &PyObject, vm: &VirtualMachine) -> PyResult<PyHash> {
 
 /// Marks a type as unhashable. Similar to PyObject_HashNotImplemented in CPython
 pub fn hash_not_implemented(zelf: &PyObject, vm: &VirtualMachine) -> PyResult<PyHash> {
-    Err(vm.new_type_error(format!("unhashable type: {}", zelf.class().name())))
+    Err(vm.new_type_error(format!("unhashable type: '{}'", zelf.class().name())))
 }
 
 fn call_wrapper(zelf: &PyObject, args: FuncArgs, vm: &VirtualMachine) -> PyResult {
@@ -540,6 +556,10 @@ impl PyType {
             }
             _ if name == identifier!(ctx, __add__) => {
                 toggle_sub_slot!(as_number, add, number_binary_op_wrapper!(__add__));
+                toggle_ext_func!(sequence_methods, concat, |seq, other, vm| concat_wrapper(
+                    seq.obj, other, vm
+                ));
+                update_pointer_slot!(as_sequence, sequence_methods);
             }
             _ if name == identifier!(ctx, __radd__) => {
                 toggle_sub_slot!(
@@ -550,6 +570,10 @@ impl PyType {
             }
             _ if name == identifier!(ctx, __iadd__) => {
                 toggle_sub_slot!(as_number, inplace_add, number_binary_op_wrapper!(__iadd__));
+                toggle_ext_func!(sequence_methods, inplace_concat, |seq, other, vm| {
+                    inplace_concat_wrapper(seq.obj, other, vm)
+                });
+                update_pointer_slot!(as_sequence, sequence_methods);
             }
             _ if name == identifier!(ctx, __sub__) => {
                 toggle_sub_slot!(as_number, subtract, number_binary_op_wrapper!(__sub__));
@@ -570,6 +594,10 @@ impl PyType {
             }
             _ if name == identifier!(ctx, __mul__) => {
                 toggle_sub_slot!(as_number, multiply, number_binary_op_wrapper!(__mul__));
+                toggle_ext_func!(sequence_methods, repeat, |seq, n, vm| repeat_wrapper(
+                    seq.obj, n, vm
+                ));
+                update_pointer_slot!(as_sequence, sequence_methods);
             }
             _ if name == identifier!(ctx, __rmul__) => {
                 toggle_sub_slot!(
@@ -584,6 +612,10 @@ impl PyType {
                     inplace_multiply,
                     number_binary_op_wrapper!(__imul__)
                 );
+                toggle_ext_func!(sequence_methods, inplace_repeat, |seq, n, vm| {
+                    inplace_repeat_wrapper(seq.obj, n, vm)
+                });
+                update_pointer_slot!(as_sequence, sequence_methods);
             }
             _ if name == identifier!(ctx, __mod__) => {
                 toggle_sub_slot!(as_number, remainder, number_binary_op_wrapper!(__mod__));
@@ -959,6 +991,35 @@ pub trait GetDescriptor: PyPayload {
     }
 }
 
+/// Native equivalent of the PEP 487 `__set_name__` descriptor hook. There's
+/// no C-level `tp_` slot for this in CPython either -- class creation just
+/// looks the method up dynamically on every class-body attribute (see the
+/// `__set_name__` loop in `PyType::new_ext`), so any pyclass can already
+/// receive the callback by hand-writing a `#[pymethod] fn __set_name__`.
+/// Implementing this trait instead gives native descriptor types (field
+/// validators, struct-backed descriptors, ...) the callback as a plain Rust
+/// method, matching the ergonomics of `Callable`/`GetDescriptor`.
+#[pyclass]
+pub trait SetName: PyPayload {
+    fn set_name(
+        zelf: &Py<Self>,
+        owner: PyTypeRef,
+        name: PyStrRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<()>;
+
+    #[inline]
+    #[pymethod]
+    fn __set_name__(
+        zelf: PyRef<Self>,
+        owner: PyTypeRef,
+        name: PyStrRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        Self::set_name(&zelf, owner, name, vm)
+    }
+}
+
 #[pyclass]
 pub trait Hashable: PyPayload {
     #[inline]
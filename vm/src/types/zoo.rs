@@ -37,6 +37,7 @@ pub struct TypeZoo {
     pub filter_type: &'static Py<PyType>,
     pub float_type: &'static Py<PyType>,
     pub frame_type: &'static Py<PyType>,
+    pub frame_locals_proxy_type: &'static Py<PyType>,
     pub frozenset_type: &'static Py<PyType>,
     pub generator_type: &'static Py<PyType>,
     pub int_type: &'static Py<PyType>,
@@ -160,6 +161,7 @@ impl TypeZoo {
             dict_reverseitemiterator_type: dict::PyDictReverseItemIterator::init_builtin_type(),
             ellipsis_type: slice::PyEllipsis::init_builtin_type(),
             frame_type: crate::frame::Frame::init_builtin_type(),
+            frame_locals_proxy_type: frame::FrameLocalsProxy::init_builtin_type(),
             function_type: function::PyFunction::init_builtin_type(),
             generator_type: generator::PyGenerator::init_builtin_type(),
             getset_type: getset::PyGetSet::init_builtin_type(),
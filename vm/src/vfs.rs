@@ -0,0 +1,68 @@
+//! A pluggable, read-only virtual filesystem for embedders that want to
+//! ship a bundled stdlib or application resources without depending on the
+//! real OS filesystem -- single-binary distributions and wasm targets are
+//! the main motivating cases.
+//!
+//! By default a [`VirtualMachine`](crate::VirtualMachine) has no virtual
+//! filesystem installed and `io.open()` goes straight to the OS as before.
+//! Embedders can install one with
+//! [`VirtualMachine::set_filesystem`](crate::VirtualMachine::set_filesystem);
+//! once installed, read-only `io.open()` calls for plain paths are served
+//! from it instead of the OS. The import machinery reads module source
+//! through `io.open_code`, so a module that `import` locates is read from
+//! the installed filesystem; locating modules still goes through
+//! `os.stat`/`os.listdir` on the real OS, so a module that exists only in
+//! the installed filesystem is not found by `import`.
+//!
+//! This only covers reading: there's no write/create/append support, since
+//! embedders reaching for this are shipping read-only bundled content, not
+//! a general-purpose writable filesystem.
+
+use std::path::Path;
+
+/// A file or directory entry's metadata (is this a file? a directory? how
+/// big is it?), as returned by [`FileSystem::metadata`].
+#[derive(Debug, Clone, Copy)]
+pub struct FileMetadata {
+    pub is_file: bool,
+    pub is_dir: bool,
+    pub len: u64,
+}
+
+/// Read-only filesystem operations over bundled content: whole-file reads,
+/// metadata lookups, and directory listings. The VM calls only
+/// [`FileSystem::read_file`] (from `io.open()`); `metadata` and `listdir`
+/// are not used by `os.stat`, `os.listdir` or the import machinery.
+pub trait FileSystem: Send + Sync {
+    fn read_file(&self, path: &Path) -> std::io::Result<Vec<u8>>;
+    fn metadata(&self, path: &Path) -> std::io::Result<FileMetadata>;
+    fn listdir(&self, path: &Path) -> std::io::Result<Vec<String>>;
+}
+
+/// The default [`FileSystem`], which defers directly to `std::fs`. Every
+/// `VirtualMachine` behaves as if this were installed until
+/// [`VirtualMachine::set_filesystem`](crate::VirtualMachine::set_filesystem)
+/// overrides it.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OsFileSystem;
+
+impl FileSystem for OsFileSystem {
+    fn read_file(&self, path: &Path) -> std::io::Result<Vec<u8>> {
+        std::fs::read(path)
+    }
+
+    fn metadata(&self, path: &Path) -> std::io::Result<FileMetadata> {
+        let meta = std::fs::metadata(path)?;
+        Ok(FileMetadata {
+            is_file: meta.is_file(),
+            is_dir: meta.is_dir(),
+            len: meta.len(),
+        })
+    }
+
+    fn listdir(&self, path: &Path) -> std::io::Result<Vec<String>> {
+        std::fs::read_dir(path)?
+            .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+            .collect()
+    }
+}
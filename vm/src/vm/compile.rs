@@ -6,6 +6,63 @@ use crate::{
     scope::Scope,
 };
 
+/// Default source encoding per [PEP 3120](https://peps.python.org/pep-3120/).
+const DEFAULT_ENCODING: &str = "utf-8";
+
+/// Decode the raw bytes of a `.py` file the way CPython's tokenizer does:
+/// an optional UTF-8 BOM is stripped (and pins the encoding to UTF-8 even
+/// if a coding cookie disagrees, matching `utf-8-sig` semantics), otherwise
+/// the first two lines are scanned for a `# -*- coding: <name> -*-` cookie
+/// per [PEP 263](https://peps.python.org/pep-0263/), defaulting to UTF-8.
+fn decode_source(data: Vec<u8>, vm: &VirtualMachine) -> PyResult<String> {
+    const BOM: &[u8] = b"\xef\xbb\xbf";
+    let (data, had_bom) = if data.starts_with(BOM) {
+        (&data[BOM.len()..], true)
+    } else {
+        (&data[..], false)
+    };
+
+    let encoding = if had_bom {
+        DEFAULT_ENCODING.to_owned()
+    } else {
+        find_coding_cookie(data).unwrap_or_else(|| DEFAULT_ENCODING.to_owned())
+    };
+
+    let bytes = vm.ctx.new_bytes(data.to_vec());
+    let decoded = vm
+        .state
+        .codec_registry
+        .decode_text(bytes.into(), &encoding, None, vm)?;
+    Ok(decoded.as_str().to_owned())
+}
+
+/// Scan up to the first two lines of `data` for a `coding:`/`coding=`
+/// declaration, as specified by PEP 263. Only ASCII-compatible prefixes of
+/// the file are inspected, same as CPython -- if the real encoding isn't
+/// ASCII-compatible in its first two lines, there's nowhere to put the
+/// cookie in the first place.
+fn find_coding_cookie(data: &[u8]) -> Option<String> {
+    for line in data.split(|&b| b == b'\n').take(2) {
+        let line = std::str::from_utf8(line).ok()?;
+        if let Some(encoding) = parse_coding_cookie(line) {
+            return Some(encoding);
+        }
+    }
+    None
+}
+
+fn parse_coding_cookie(line: &str) -> Option<String> {
+    let idx = line.find("coding")?;
+    let rest = line[idx + "coding".len()..].trim_start();
+    let rest = rest.strip_prefix([':', '='])?;
+    let rest = rest.trim_start();
+    let encoding: String = rest
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect();
+    (!encoding.is_empty()).then_some(encoding)
+}
+
 impl VirtualMachine {
     pub fn compile(
         &self,
@@ -44,8 +101,9 @@ impl VirtualMachine {
             self.insert_sys_path(self.new_pyobj(dir))?;
         }
 
-        match std::fs::read_to_string(path) {
-            Ok(source) => {
+        match std::fs::read(path) {
+            Ok(data) => {
+                let source = decode_source(data, self)?;
                 self.run_code_string(scope, &source, path.to_owned())?;
             }
             Err(err) => {
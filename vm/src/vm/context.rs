@@ -1,5 +1,5 @@
 use crate::{
-    PyResult, VirtualMachine,
+    AsObject, PyResult, VirtualMachine,
     builtins::{
         PyBaseException, PyByteArray, PyBytes, PyComplex, PyDict, PyDictRef, PyEllipsis, PyFloat,
         PyFrozenSet, PyInt, PyIntRef, PyList, PyListRef, PyNone, PyNotImplemented, PyStr,
@@ -50,6 +50,16 @@ pub struct Context {
     pub(crate) string_pool: StringPool,
     pub(crate) slot_new_wrapper: PyMethodDef,
     pub names: ConstName,
+    /// Dedup cache for tuple and bytes constants materialized by
+    /// [`crate::builtins::code::PyObjBag`], keyed by structural content so
+    /// identical literals compiled into different code objects -- notably
+    /// the many separately-frozen stdlib modules -- end up sharing one
+    /// object instead of allocating a fresh copy per module.
+    pub(crate) tuple_const_pool:
+        crate::common::lock::PyMutex<std::collections::HashMap<crate::bytecode::ConstantData, PyTupleRef>>,
+    pub(crate) bytes_const_pool: crate::common::lock::PyMutex<
+        std::collections::HashMap<crate::bytecode::ConstantData, PyRef<crate::builtins::PyBytes>>,
+    >,
 }
 
 macro_rules! declare_const_name {
@@ -281,37 +291,51 @@ impl Context {
             PyRef::new_ref(payload, cls.to_owned(), None)
         }
 
-        let none = create_object(PyNone, PyNone::static_type());
-        let ellipsis = create_object(PyEllipsis, PyEllipsis::static_type());
-        let not_implemented = create_object(PyNotImplemented, PyNotImplemented::static_type());
+        // Singletons and the small-int cache outlive every interpreter they're handed to
+        // (they're owned by this `'static` genesis `Context`), so mark them immortal per
+        // PEP 683: it's not just an optimization for them specifically, it also means the
+        // extremely hot `None`/`True`/`False`/small-int refcounts stop bouncing a shared
+        // cache line between threads on every inc/dec.
+        macro_rules! immortal {
+            ($obj:expr) => {{
+                let obj = $obj;
+                unsafe { obj.as_object().mark_immortal() };
+                obj
+            }};
+        }
+
+        let none = immortal!(create_object(PyNone, PyNone::static_type()));
+        let ellipsis = immortal!(create_object(PyEllipsis, PyEllipsis::static_type()));
+        let not_implemented =
+            immortal!(create_object(PyNotImplemented, PyNotImplemented::static_type()));
 
-        let typing_no_default = create_object(
+        let typing_no_default = immortal!(create_object(
             crate::stdlib::typing::NoDefault,
             crate::stdlib::typing::NoDefault::static_type(),
-        );
+        ));
 
         let int_cache_pool = Self::INT_CACHE_POOL_RANGE
             .map(|v| {
-                PyRef::new_ref(
+                immortal!(PyRef::new_ref(
                     PyInt::from(BigInt::from(v)),
                     types.int_type.to_owned(),
                     None,
-                )
+                ))
             })
             .collect();
 
-        let true_value = create_object(PyInt::from(1), types.bool_type);
-        let false_value = create_object(PyInt::from(0), types.bool_type);
+        let true_value = immortal!(create_object(PyInt::from(1), types.bool_type));
+        let false_value = immortal!(create_object(PyInt::from(0), types.bool_type));
 
-        let empty_tuple = create_object(
+        let empty_tuple = immortal!(create_object(
             PyTuple::new_unchecked(Vec::new().into_boxed_slice()),
             types.tuple_type,
-        );
-        let empty_frozenset = PyRef::new_ref(
+        ));
+        let empty_frozenset = immortal!(PyRef::new_ref(
             PyFrozenSet::default(),
             types.frozenset_type.to_owned(),
             None,
-        );
+        ));
 
         let string_pool = StringPool::default();
         let names = unsafe { ConstName::new(&string_pool, &types.str_type.to_owned()) };
@@ -324,7 +348,7 @@ impl Context {
         );
 
         let empty_str = unsafe { string_pool.intern("", types.str_type.to_owned()) };
-        let empty_bytes = create_object(PyBytes::from(Vec::new()), types.bytes_type);
+        let empty_bytes = immortal!(create_object(PyBytes::from(Vec::new()), types.bytes_type));
         Self {
             true_value,
             false_value,
@@ -344,6 +368,8 @@ impl Context {
             string_pool,
             slot_new_wrapper,
             names,
+            tuple_const_pool: crate::common::lock::PyMutex::new(std::collections::HashMap::new()),
+            bytes_const_pool: crate::common::lock::PyMutex::new(std::collections::HashMap::new()),
         }
     }
 
@@ -56,6 +56,30 @@ impl Interpreter {
         Self { vm }
     }
 
+    /// Like [`Self::with_init`], but also returns a
+    /// [`crate::signal::InterpreterHandle`] that another thread can use to
+    /// interrupt this interpreter -- with a `KeyboardInterrupt` or a custom
+    /// exception -- while it's running a script.
+    /// ```
+    /// use rustpython_vm::Interpreter;
+    /// let (interp, handle) = Interpreter::with_init_and_handle(Default::default(), |_vm| {});
+    /// handle.interrupt().unwrap();
+    /// ```
+    pub fn with_init_and_handle<F>(
+        settings: Settings,
+        init: F,
+    ) -> (Self, crate::signal::InterpreterHandle)
+    where
+        F: FnOnce(&mut VirtualMachine),
+    {
+        let (tx, rx) = crate::signal::user_signal_channel();
+        let interp = Self::with_init(settings, |vm| {
+            vm.set_user_signal_channel(rx);
+            init(vm);
+        });
+        (interp, crate::signal::InterpreterHandle::new(tx))
+    }
+
     /// Run a function with the main virtual machine and return a PyResult of the result.
     ///
     /// To enter vm context multiple times or to avoid buffer/exception management, this function is preferred.
@@ -126,6 +150,9 @@ impl Interpreter {
 
             atexit::_run_exitfuncs(vm);
 
+            #[cfg(feature = "pystats")]
+            crate::stats::dump();
+
             vm.state.finalizing.store(true, Ordering::Release);
 
             vm.flush_std();
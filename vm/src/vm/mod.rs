@@ -44,6 +44,7 @@ use std::{
     collections::{HashMap, HashSet},
     ffi::{OsStr, OsString},
     sync::atomic::AtomicBool,
+    time::Instant,
 };
 
 pub use context::Context;
@@ -53,6 +54,12 @@ pub use setting::{CheckHashPycsMode, Settings};
 
 pub const MAX_MEMORY_SIZE: usize = isize::MAX as usize;
 
+/// Minimum amount of real stack space [`VirtualMachine::check_recursive_call`]
+/// requires before letting another level of native recursion proceed,
+/// regardless of how high `sys.setrecursionlimit` was set.
+#[cfg(all(feature = "stack-headroom", not(target_arch = "wasm32")))]
+const RECURSION_STACK_RED_ZONE: usize = 256 * 1024;
+
 // Objects are live when they are on stack, or referenced by a name (for now)
 
 /// Top level container of a python virtual machine. In theory you could
@@ -77,6 +84,23 @@ pub struct VirtualMachine {
     pub state: PyRc<PyGlobalState>,
     pub initialized: bool,
     recursion_depth: Cell<usize>,
+    instructions_remaining: Cell<Option<u64>>,
+    deadline: Cell<Option<Instant>>,
+    memory_limit: Cell<Option<usize>>,
+    pub(crate) filesystem: Option<Box<dyn crate::vfs::FileSystem>>,
+}
+
+/// A limit on how much work a single `run_code_obj`-style call may do
+/// before it's aborted with a catchable `TimeoutError`, instead of
+/// running forever. Set with [`VirtualMachine::set_execution_budget`] or
+/// [`VirtualMachine::run_code_obj_with_budget`]; checked once per
+/// bytecode instruction alongside [`VirtualMachine::check_signals`], so a
+/// `while True: pass` submitted by an untrusted plugin can be stopped
+/// without needing `signal.alarm` or a native OS thread to police it.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ExecutionBudget {
+    pub max_instructions: Option<u64>,
+    pub deadline: Option<Instant>,
 }
 
 #[derive(Debug, Default)]
@@ -102,6 +126,26 @@ pub struct PyGlobalState {
     pub after_forkers_parent: PyMutex<Vec<PyObjectRef>>,
     pub int_max_str_digits: AtomicCell<usize>,
     pub switch_interval: AtomicCell<f64>,
+    pub audit_hooks: PyMutex<Vec<PyObjectRef>>,
+    pub module_providers: PyMutex<Vec<(String, Box<dyn crate::import::ModuleProvider>)>>,
+    /// `Some` while an embedder is recording a warm-start profile (see
+    /// [`VirtualMachine::start_import_profile`]); holds every module name
+    /// that has passed through `import_inner`, in first-imported order.
+    pub import_profile: PyMutex<Option<indexmap::IndexSet<String>>>,
+    /// Set to `false` by `InterpreterConfig::allow_subprocess(false)`. Unlike
+    /// that toggle's module-registration denylist, this is checked directly
+    /// by the handful of `os`/`posix`/`nt` functions (`system`, `fork`,
+    /// `exec*`) that spawn processes without going through a deniable
+    /// module, since those modules are always present for importlib.
+    pub subprocess_allowed: AtomicBool,
+    /// Set to `false` by `InterpreterConfig::allow_filesystem(false)`. Like
+    /// [`Self::subprocess_allowed`], this is checked directly by the
+    /// `os`/`posix`/`nt`/`io` functions that touch the real filesystem
+    /// (`open`, `remove`/`unlink`, `rename`/`replace`, `mkdir`, `rmdir`,
+    /// `listdir`, `io.open`), since those modules are always present for
+    /// importlib. An embedder-installed [`crate::vfs`] filesystem is
+    /// unaffected -- it's already a sandboxing mechanism in its own right.
+    pub filesystem_allowed: AtomicBool,
 }
 
 pub fn process_hash_secret_seed() -> u32 {
@@ -166,6 +210,9 @@ impl VirtualMachine {
             trace_func,
             use_tracing: Cell::new(false),
             recursion_limit: Cell::new(if cfg!(debug_assertions) { 256 } else { 1000 }),
+            instructions_remaining: Cell::new(None),
+            deadline: Cell::new(None),
+            memory_limit: Cell::new(None),
             signal_handlers,
             signal_rx: None,
             repr_guards: RefCell::default(),
@@ -186,9 +233,15 @@ impl VirtualMachine {
                 after_forkers_parent: PyMutex::default(),
                 int_max_str_digits,
                 switch_interval: AtomicCell::new(0.005),
+                audit_hooks: PyMutex::default(),
+                module_providers: PyMutex::default(),
+                import_profile: PyMutex::new(None),
+                subprocess_allowed: AtomicBool::new(true),
+                filesystem_allowed: AtomicBool::new(true),
             }),
             initialized: false,
             recursion_depth: Cell::new(0),
+            filesystem: None,
         };
 
         if vm.state.hash_secret.hash_str("")
@@ -445,6 +498,87 @@ impl VirtualMachine {
         self.signal_rx = Some(signal_rx);
     }
 
+    /// Install a custom [`FileSystem`](crate::vfs::FileSystem) for plain,
+    /// read-only `io.open()` calls (and therefore the import machinery) to
+    /// read through, instead of the real OS filesystem. See [`crate::vfs`]
+    /// for what this does and doesn't cover.
+    ///
+    /// Can only be used in the initialization closure passed to
+    /// [`Interpreter::with_init`].
+    pub fn set_filesystem(&mut self, filesystem: impl crate::vfs::FileSystem + 'static) {
+        self.filesystem = Some(Box::new(filesystem));
+    }
+
+    /// Register a callable as a [PEP 578](https://peps.python.org/pep-0578/)
+    /// audit hook. It's called with `(event, args)` for every event raised
+    /// with [`VirtualMachine::audit`] -- including from `sys.audit()` called
+    /// from Python -- and can veto the operation by raising.
+    ///
+    /// Equivalent to calling `sys.addaudithook` from Rust, which is useful
+    /// for embedders that want to log or block sensitive operations without
+    /// needing a `sys` import hook of their own.
+    pub fn add_audit_hook(&self, hook: PyObjectRef) {
+        self.state.audit_hooks.lock().push(hook);
+    }
+
+    /// Raise an auditing event, as `sys.audit(event, *args)` does. Calls
+    /// every hook registered with [`VirtualMachine::add_audit_hook`] in
+    /// registration order, passing `(event, args)`; if a hook raises, that
+    /// error is returned immediately and later hooks are not run, which lets
+    /// a hook veto the operation the event describes.
+    pub fn audit(&self, event: &str, args: Vec<PyObjectRef>) -> PyResult<()> {
+        // Clone the hook list and drop the lock before calling into Python,
+        // since a hook could itself raise another audited event.
+        let hooks = self.state.audit_hooks.lock().clone();
+        if hooks.is_empty() {
+            return Ok(());
+        }
+        let args_tuple: PyObjectRef = self.ctx.new_tuple(args).into();
+        for hook in hooks {
+            hook.call((event, args_tuple.clone()), self)?;
+        }
+        Ok(())
+    }
+
+    /// Register a [`ModuleProvider`](crate::import::ModuleProvider) to back
+    /// imports of `prefix` and any of its dotted submodules (`prefix.foo`,
+    /// `prefix.foo.bar`, ...) -- both `import prefix.foo` and a plain
+    /// `import prefix` resolve through it. Installed ahead of `PathFinder`
+    /// on `sys.meta_path`, so this lets embedders expose `myapp.*` modules
+    /// backed by Rust or embedded resources without writing a Python finder.
+    pub fn register_module_provider(
+        &self,
+        prefix: impl Into<String>,
+        provider: impl crate::import::ModuleProvider + 'static,
+    ) {
+        self.state
+            .module_providers
+            .lock()
+            .push((prefix.into(), Box::new(provider)));
+    }
+
+    /// Convert a [`PyObject`] into any [`serde::de::DeserializeOwned`] Rust
+    /// value (dict/list/str/int/float/bool/None, plus structs/enums derived
+    /// from those), so embedders can pull structured data out of Python
+    /// without manually downcasting every field.
+    #[cfg(feature = "serde")]
+    pub fn to_serde<T>(&self, obj: &PyObject) -> PyResult<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        crate::py_serde::from_pyobject(self, obj)
+    }
+
+    /// Convert any [`serde::Serialize`] Rust value into a [`PyObjectRef`],
+    /// the reverse of [`Self::to_serde`].
+    #[cfg(feature = "serde")]
+    pub fn from_serde<T>(&self, value: &T) -> PyResult<PyObjectRef>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        crate::py_serde::to_pyobject(self, value)
+    }
+
     pub fn run_code_obj(&self, code: PyRef<PyCode>, scope: Scope) -> PyResult {
         use crate::builtins::PyFunction;
 
@@ -457,6 +591,113 @@ impl VirtualMachine {
         self.run_frame(frame)
     }
 
+    /// Arm the per-instruction budget check (see [`Self::check_execution_budget`])
+    /// with a new limit, replacing whatever limit was previously set.
+    pub fn set_execution_budget(&self, budget: ExecutionBudget) {
+        self.instructions_remaining.set(budget.max_instructions);
+        self.deadline.set(budget.deadline);
+    }
+
+    /// Disarm the per-instruction budget check, letting execution run
+    /// unbounded again.
+    pub fn clear_execution_budget(&self) {
+        self.instructions_remaining.set(None);
+        self.deadline.set(None);
+    }
+
+    /// Checked once per bytecode instruction, alongside [`Self::check_signals`].
+    /// Returns a catchable `TimeoutError` the first time the instruction
+    /// count set by [`Self::set_execution_budget`] is exhausted or its
+    /// deadline has passed.
+    pub(crate) fn check_execution_budget(&self) -> PyResult<()> {
+        if let Some(remaining) = self.instructions_remaining.get() {
+            if remaining == 0 {
+                return Err(self.new_timeout_error("instruction budget exceeded".to_owned()));
+            }
+            self.instructions_remaining.set(Some(remaining - 1));
+        }
+        if let Some(deadline) = self.deadline.get() {
+            if Instant::now() >= deadline {
+                return Err(self.new_timeout_error("execution deadline exceeded".to_owned()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Run `code` as with [`Self::run_code_obj`], but abort with a
+    /// `TimeoutError` if `budget` is exhausted before the code finishes.
+    /// Useful for embedders running untrusted or plugin code, where a
+    /// `while True: pass` shouldn't be able to hang the host.
+    pub fn run_code_obj_with_budget(
+        &self,
+        code: PyRef<PyCode>,
+        scope: Scope,
+        budget: ExecutionBudget,
+    ) -> PyResult {
+        self.set_execution_budget(budget);
+        let result = self.run_code_obj(code, scope);
+        self.clear_execution_budget();
+        result
+    }
+
+    /// Bytes currently live across every Python object in the process (see
+    /// [`crate::object::bytes_allocated`] for exactly what's counted). Handy
+    /// for an embedder monitoring how much a plugin workload is costing,
+    /// even though -- since objects aren't tagged with the `VirtualMachine`
+    /// that created them -- it reports the whole process rather than just
+    /// this `VirtualMachine`.
+    pub fn memory_usage(&self) -> usize {
+        crate::object::bytes_allocated()
+    }
+
+    /// Number of `PyObject`s currently live across the process, same scope
+    /// caveat as [`Self::memory_usage`]. Exposed as `sys.getallocatedblocks`.
+    pub fn allocated_blocks(&self) -> usize {
+        crate::object::allocated_blocks()
+    }
+
+    /// Start recording every module name this `VirtualMachine` imports, for
+    /// building a warm-start freezing manifest: run the application once
+    /// with this on, then feed [`Self::take_import_profile`]'s output
+    /// (module names, in first-imported order) to `py_freeze!`/the snapshot
+    /// build step so the next build embeds exactly what this run touched,
+    /// instead of a hand-curated module list.
+    ///
+    /// Recording resets (and replaces) any profile already in progress.
+    pub fn start_import_profile(&self) {
+        *self.state.import_profile.lock() = Some(indexmap::IndexSet::new());
+    }
+
+    /// Stop recording and return the modules collected since the matching
+    /// [`Self::start_import_profile`], or `None` if no recording was active.
+    pub fn take_import_profile(&self) -> Option<Vec<String>> {
+        self.state
+            .import_profile
+            .lock()
+            .take()
+            .map(|modules| modules.into_iter().collect())
+    }
+
+    /// Cap [`Self::memory_usage`] can grow to before
+    /// [`Self::check_memory_limit`] starts raising `MemoryError`, replacing
+    /// any limit set previously. `None` means unbounded.
+    pub fn set_memory_limit(&self, limit: Option<usize>) {
+        self.memory_limit.set(limit);
+    }
+
+    /// Checked once per bytecode instruction, alongside
+    /// [`Self::check_execution_budget`].
+    pub(crate) fn check_memory_limit(&self) -> PyResult<()> {
+        if let Some(limit) = self.memory_limit.get()
+            && self.memory_usage() > limit
+        {
+            return Err(self.new_memory_error(format!(
+                "memory usage exceeded the configured limit of {limit} bytes"
+            )));
+        }
+        Ok(())
+    }
+
     #[cold]
     pub fn run_unraisable(&self, e: PyBaseExceptionRef, msg: Option<String>, object: PyObjectRef) {
         let sys_module = self.import("sys", 0).unwrap();
@@ -526,10 +767,31 @@ impl VirtualMachine {
     // To be called right before raising the recursion depth.
     fn check_recursive_call(&self, _where: &str) -> PyResult<()> {
         if self.recursion_depth.get() >= self.recursion_limit.get() {
-            Err(self.new_recursion_error(format!("maximum recursion depth exceeded {_where}")))
-        } else {
-            Ok(())
+            return Err(
+                self.new_recursion_error(format!("maximum recursion depth exceeded {_where}"))
+            );
+        }
+        // `recursion_limit` is just a counter configurable via
+        // `sys.setrecursionlimit`; raising it doesn't grow the thread's real
+        // stack, so on its own it can't stop a high-enough limit from
+        // letting native recursion (frame pushes, `__repr__`/comparison
+        // dispatch, ...) overrun the actual Rust stack and abort the process
+        // before the counter ever catches it. Treat running low on real
+        // stack as the same condition, independently of the configured
+        // limit, so it surfaces as a catchable `RecursionError` instead.
+        //
+        // Gated behind `stack-headroom` (off by default until its
+        // `stacker` dependency is synced into Cargo.lock): without it we
+        // fall back to the counter-only behavior this block is meant to
+        // backstop.
+        #[cfg(all(feature = "stack-headroom", not(target_arch = "wasm32")))]
+        if stacker::remaining_stack().is_some_and(|remaining| remaining < RECURSION_STACK_RED_ZONE)
+        {
+            return Err(
+                self.new_recursion_error(format!("maximum recursion depth exceeded {_where}"))
+            );
         }
+        Ok(())
     }
 
     pub fn current_frame(&self) -> Option<Ref<'_, FrameRef>> {
@@ -607,6 +869,10 @@ impl VirtualMachine {
         from_list: &Py<PyTuple<PyStrRef>>,
         level: usize,
     ) -> PyResult {
+        if let Some(modules) = self.state.import_profile.lock().as_mut() {
+            modules.insert(module.as_str().to_owned());
+        }
+
         // if the import inputs seem weird, e.g a package import or something, rather than just
         // a straight `import ident`
         let weird = module.as_str().contains('.') || level != 0 || !from_list.is_empty();
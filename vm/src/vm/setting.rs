@@ -30,7 +30,9 @@ pub struct Settings {
     // int malloc_stats;
     // wchar_t *filesystem_encoding;
     // wchar_t *filesystem_errors;
-    // wchar_t *pycache_prefix;
+    /// PYTHONPYCACHEPREFIX=x
+    pub pycache_prefix: Option<String>,
+
     // int parse_argv;
     // PyWideStringList orig_argv;
     /// sys.argv
@@ -157,6 +159,7 @@ impl Default for Settings {
             path_list: vec![],
             argv: vec![],
             hash_seed: None,
+            pycache_prefix: None,
             buffered_stdio: true,
             check_hash_pycs_mode: CheckHashPycsMode::Default,
             allow_external_library: cfg!(feature = "importlib"),
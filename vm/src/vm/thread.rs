@@ -23,6 +23,13 @@ pub fn with_current_vm<R>(f: impl FnOnce(&VirtualMachine) -> R) -> R {
     VM_CURRENT.with(f)
 }
 
+/// Like [`with_current_vm`], but returns `None` instead of panicking when called from a
+/// thread that isn't currently running a `VirtualMachine` (e.g. a signal handler that fired
+/// on a thread the interpreter never entered).
+pub fn try_with_current_vm<R>(f: impl FnOnce(&VirtualMachine) -> R) -> Option<R> {
+    VM_CURRENT.is_set().then(|| VM_CURRENT.with(f))
+}
+
 pub fn enter_vm<R>(vm: &VirtualMachine, f: impl FnOnce() -> R) -> R {
     VM_STACK.with(|vms| {
         vms.borrow_mut().push(vm.into());
@@ -548,6 +548,7 @@ impl VirtualMachine {
     define_exception_fn!(fn new_attribute_error, attribute_error, AttributeError);
     define_exception_fn!(fn new_type_error, type_error, TypeError);
     define_exception_fn!(fn new_os_error, os_error, OSError);
+    define_exception_fn!(fn new_permission_error, permission_error, PermissionError);
     define_exception_fn!(fn new_system_error, system_error, SystemError);
 
     // TODO: remove & replace with new_unicode_decode_error_real
@@ -570,4 +571,5 @@ impl VirtualMachine {
     define_exception_fn!(fn new_overflow_error, overflow_error, OverflowError);
     define_exception_fn!(fn new_runtime_error, runtime_error, RuntimeError);
     define_exception_fn!(fn new_memory_error, memory_error, MemoryError);
+    define_exception_fn!(fn new_timeout_error, timeout_error, TimeoutError);
 }